@@ -0,0 +1,135 @@
+use chrono::{DateTime, Datelike, FixedOffset, ParseError, Weekday};
+use std::error;
+use std::fmt;
+
+use FixKind;
+
+/// Errors returned when a date could not be recovered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input failed to parse, both as is and after sanitization.
+    Unparseable {
+        /// Error from parsing the input as is.
+        original: ParseError,
+        /// Error from parsing the sanitized input.
+        sanitized: ParseError,
+        /// The sanitized string that was tried.
+        sanitized_input: String,
+        /// The last fixup that changed the input before giving up,
+        /// `None` if no fixup applied.
+        stage: Option<FixKind>,
+    },
+    /// The input named a weekday the date does not fall on,
+    /// and `WeekdayPolicy::Reject` was in effect.
+    WeekdayMismatch {
+        /// The weekday named by the input.
+        weekday: Weekday,
+        /// The date, as parsed without the weekday.
+        datetime: DateTime<FixedOffset>,
+    },
+    /// A comment named a timezone that does not match the offset of the
+    /// date, and `Sanitizer::check_zone_comment` was enabled.
+    ZoneMismatch {
+        /// The timezone named by the comment.
+        zone: String,
+        /// The date, with the offset it gave.
+        datetime: DateTime<FixedOffset>,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Unparseable {
+                ref original,
+                ref sanitized,
+                ref sanitized_input,
+                stage,
+            } => {
+                write!(f, "invalid date ({})", original)?;
+                match stage {
+                    Some(stage) => write!(
+                        f,
+                        ", still invalid after {}: \"{}\" ({})",
+                        stage, sanitized_input, sanitized
+                    ),
+                    None => write!(f, ", no fixup applied"),
+                }
+            }
+            Error::WeekdayMismatch { weekday, datetime } => write!(
+                f,
+                "date says {} but {} is a {}",
+                weekday,
+                datetime.format("%d %b %Y"),
+                datetime.weekday()
+            ),
+            Error::ZoneMismatch { ref zone, datetime } => write!(
+                f,
+                "date says {} but its offset is {}",
+                zone,
+                datetime.format("%z")
+            ),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            Error::Unparseable { ref sanitized, .. } => Some(sanitized),
+            Error::WeekdayMismatch { .. } | Error::ZoneMismatch { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parse_from_rfc2822_with_fallback;
+
+    #[test]
+    fn test_unparseable() {
+        let err = parse_from_rfc2822_with_fallback("Mon, 31 July 2017").unwrap_err();
+        match err {
+            Error::Unparseable {
+                ref sanitized_input,
+                stage,
+                ..
+            } => {
+                assert_eq!(sanitized_input, "31 Jul 2017");
+                assert_eq!(stage, Some(FixKind::ReplaceMonth));
+            }
+            _ => panic!("unexpected error: {:?}", err),
+        }
+        assert_eq!(
+            err.to_string(),
+            "invalid date (input contains invalid characters), \
+             still invalid after month replacement: \"31 Jul 2017\" \
+             (premature end of input)"
+        );
+
+        let err = parse_from_rfc2822_with_fallback("yesterday").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid date (input contains invalid characters), no fixup applied"
+        );
+    }
+
+    #[test]
+    fn test_weekday_mismatch() {
+        let err = Error::WeekdayMismatch {
+            weekday: Weekday::Thu,
+            datetime: DateTime::parse_from_rfc2822("05 Aug 2016 06:00:00 -0400").unwrap(),
+        };
+        assert_eq!(err.to_string(), "date says Thu but 05 Aug 2016 is a Fri");
+    }
+
+    #[test]
+    fn test_zone_mismatch() {
+        let err = Error::ZoneMismatch {
+            zone: "PDT".to_string(),
+            datetime: DateTime::parse_from_rfc2822("31 Jul 2017 16:00:00 -0800").unwrap(),
+        };
+        assert_eq!(err.to_string(), "date says PDT but its offset is -0800");
+    }
+}
@@ -0,0 +1,153 @@
+//! Formats tried once the date is not RFC 2822, even after sanitizing.
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone};
+
+use sanitizer::Format;
+
+/// Epoch seconds accepted by `parse_epoch`, 1980-01-01 to 2100-01-01.
+const EPOCH_BOUNDS: (i64, i64) = (315_532_800, 4_102_444_800);
+
+/// `strftime` layouts of ISO 8601 dates with an offset, once the
+/// date/time separator is a `T` and `Z` is spelled `+00:00`.
+static ISO8601_FORMATS: &[&str] = &["%Y-%m-%dT%H:%M:%S%.f%z", "%Y-%m-%dT%H:%M%z"];
+
+/// Same as `ISO8601_FORMATS` for dates without an offset.
+static ISO8601_LOCAL_FORMATS: &[&str] = &["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%dT%H:%M"];
+
+/// Parse RFC 3339 dates, like "2017-07-31T16:00:00Z".
+pub(crate) fn parse_rfc3339(s: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(s.trim()).ok()
+}
+
+/// Parse the ISO 8601 dates RFC 3339 does not allow: a space or lowercase
+/// `t` between date and time, no seconds, no colon in the offset, or no
+/// time or offset at all.
+///
+/// Dates without an offset are taken to be at `offset`, and rejected
+/// when there is none.
+pub(crate) fn parse_iso8601(s: &str, offset: Option<FixedOffset>) -> Option<DateTime<FixedOffset>> {
+    let s = s.trim();
+    // The separator is the first space or `t` right after the date.
+    let mut s = match s.get(10..11) {
+        Some(" ") | Some("t") => format!("{}T{}", &s[..10], s[11..].trim_start()),
+        _ => s.to_string(),
+    };
+    if s.ends_with('Z') || s.ends_with('z') {
+        s.pop();
+        s.push_str("+00:00");
+    }
+
+    let with_offset = ISO8601_FORMATS
+        .iter()
+        .filter_map(|fmt| DateTime::parse_from_str(&s, fmt).ok())
+        .next();
+    if with_offset.is_some() {
+        return with_offset;
+    }
+
+    let offset = offset?;
+    let local = ISO8601_LOCAL_FORMATS
+        .iter()
+        .filter_map(|fmt| NaiveDateTime::parse_from_str(&s, fmt).ok())
+        .next()
+        .or_else(|| {
+            NaiveDate::parse_from_str(&s, "%Y-%m-%d")
+                .ok()
+                .map(|d| d.and_hms(0, 0, 0))
+        })?;
+    offset.from_local_datetime(&local).single()
+}
+
+/// Parse seconds or milliseconds since the Unix epoch, like "1501516800".
+///
+/// Only 9 or 10 digit seconds and 12 or 13 digit milliseconds, that land
+/// between 1980 and 2100, are taken for a date.
+pub(crate) fn parse_epoch(s: &str) -> Option<(DateTime<FixedOffset>, Format)> {
+    let s = s.trim();
+    if !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let (secs, nanos, format) = match s.len() {
+        9 | 10 => (s.parse().ok()?, 0, Format::EpochSeconds),
+        12 | 13 => {
+            let millis: i64 = s.parse().ok()?;
+            let nanos = (millis % 1000) as u32 * 1_000_000;
+            (millis / 1000, nanos, Format::EpochMillis)
+        }
+        _ => return None,
+    };
+    if secs < EPOCH_BOUNDS.0 || secs >= EPOCH_BOUNDS.1 {
+        return None;
+    }
+
+    let datetime = FixedOffset::east(0).timestamp_opt(secs, nanos).single()?;
+    Some((datetime, format))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_iso8601() {
+        let utc = Some(FixedOffset::east(0));
+        let dt = DateTime::parse_from_rfc3339("2017-07-31T16:00:00+00:00").unwrap();
+        let foo = [
+            "2017-07-31T16:00:00Z",
+            "2017-07-31 16:00:00Z",
+            "2017-07-31t16:00:00z",
+            "2017-07-31T16:00:00+0000",
+            "2017-07-31T18:00:00+02:00",
+            "2017-07-31T16:00Z",
+            "2017-07-31 16:00:00",
+            "2017-07-31T16:00",
+            " 2017-07-31  16:00:00.000 ",
+        ];
+
+        foo.iter()
+            .for_each(|s| assert_eq!(parse_iso8601(s, utc), Some(dt), "{}", s));
+
+        assert_eq!(
+            parse_iso8601("2017-07-31", Some(FixedOffset::east(2 * 3600))),
+            DateTime::parse_from_rfc3339("2017-07-31T00:00:00+02:00").ok()
+        );
+        assert_eq!(parse_iso8601("2017-07-31 16:00:00", None), None);
+        assert_eq!(parse_iso8601("2017-07-31", None), None);
+        assert_eq!(parse_iso8601("2017-07-31 16:00:00Z", None), Some(dt));
+        assert_eq!(parse_iso8601("2017-07-31 25:00:00", utc), None);
+        assert_eq!(parse_iso8601("31 Jul 2017 16:00:00", utc), None);
+        assert_eq!(parse_iso8601("yesterday", utc), None);
+    }
+
+    #[test]
+    fn test_parse_epoch() {
+        let dt = DateTime::parse_from_rfc3339("2017-07-31T16:00:00+00:00").unwrap();
+        assert_eq!(parse_epoch("1501516800"), Some((dt, Format::EpochSeconds)));
+        assert_eq!(
+            parse_epoch(" 1501516800 "),
+            Some((dt, Format::EpochSeconds))
+        );
+        assert_eq!(
+            parse_epoch("1501516800000"),
+            Some((dt, Format::EpochMillis))
+        );
+
+        let dt = DateTime::parse_from_rfc3339("2017-07-31T16:00:00.123+00:00").unwrap();
+        assert_eq!(
+            parse_epoch("1501516800123"),
+            Some((dt, Format::EpochMillis))
+        );
+
+        // Too short, too long, or out of bounds.
+        assert_eq!(parse_epoch("20170731"), None);
+        assert_eq!(parse_epoch("15015168000"), None);
+        assert_eq!(parse_epoch("15015168000000"), None);
+        assert_eq!(parse_epoch("100000000"), None);
+        assert_eq!(parse_epoch("9999999999"), None);
+        assert_eq!(parse_epoch("100000000000"), None);
+        assert_eq!(parse_epoch("-1501516800"), None);
+        assert_eq!(parse_epoch("1501516800.5"), None);
+        assert_eq!(parse_epoch(""), None);
+    }
+}
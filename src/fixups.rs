@@ -0,0 +1,112 @@
+//! The built-in stages of the sanitizer pipeline.
+//!
+//! Each one wraps one of the repairs `sanitize_rfc822_like_date` applies,
+//! so they can be reordered or mixed with your own in a `Sanitizer`.
+//! `DecodeHtml` is the exception: it is not part of the default pipeline
+//! and has to be added explicitly.
+
+use sanitizer::{Context, Fixup};
+use FixKind;
+
+macro_rules! builtin {
+    ($(#[$attr:meta])* $name:ident => $func:path) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, Default)]
+        pub struct $name;
+
+        impl Fixup for $name {
+            fn kind(&self) -> FixKind {
+                FixKind::$name
+            }
+
+            fn apply(&self, s: String, cx: &mut Context) -> String {
+                $func(s, cx)
+            }
+        }
+    };
+}
+
+builtin!(
+    /// Decode HTML entities and remove CDATA markers.
+    ///
+    /// Not part of `Sanitizer::new()`, for dates that went through sloppy
+    /// XML handling it is best run first:
+    ///
+    /// ```rust
+    /// # extern crate rfc822_sanitizer;
+    /// use rfc822_sanitizer::fixups::DecodeHtml;
+    /// use rfc822_sanitizer::{FixKind, Sanitizer};
+    ///
+    /// # fn main() {
+    /// let sanitizer = Sanitizer::new().before(FixKind::NormalizeWhitespace, DecodeHtml);
+    /// assert_eq!(
+    ///     sanitizer
+    ///         .sanitize("<![CDATA[Mon,&nbsp;31&#32;Jul 2017 16:00:00 +0000]]>")
+    ///         .sanitized,
+    ///     "31 Jul 2017 16:00:00 +0000"
+    /// );
+    /// # }
+    /// ```
+    DecodeHtml => ::decode_html
+);
+builtin!(
+    /// Collapse whitespace and remove invisible characters.
+    NormalizeWhitespace => ::normalize_whitespace
+);
+builtin!(
+    /// Remove RFC 5322 comments, like "(PDT)". A trailing timezone comment
+    /// that is the only timezone of the date is replaced with its offset.
+    StripComments => ::strip_comments
+);
+builtin!(
+    /// Remove fractional seconds from the time.
+    StripFraction => ::strip_fraction
+);
+builtin!(
+    /// Convert 12-hour AM/PM times to 24-hour.
+    ConvertMeridiem => ::convert_meridiem
+);
+builtin!(
+    /// Pad HH:MM:SS and HH:MM with extra zeros if needed.
+    PadZeros => ::pad_zeros
+);
+builtin!(
+    /// Append `:00` seconds to `HH:MM` times.
+    AddSeconds => ::add_seconds
+);
+builtin!(
+    /// Remove weekday names anywhere in the date, in any supported language.
+    RemoveWeekday => ::remove_weekday
+);
+builtin!(
+    /// Remove ordinal suffixes, like "st" in "31st", from the day.
+    StripOrdinal => ::strip_ordinal
+);
+builtin!(
+    /// Replace month names, in any supported language, with their RFC 2822 abbreviation.
+    ReplaceMonth => ::replace_month
+);
+builtin!(
+    /// Rewrite month-first dates in day, month, year order.
+    ReorderDate => ::reorder_date
+);
+builtin!(
+    /// Expand two and three digit years to four digits.
+    ExpandYear => ::expand_year
+);
+builtin!(
+    /// Convert `±HH:MM`, `±HH` and `±H` offsets to `±HHMM`.
+    NormalizeOffset => ::normalize_offset
+);
+builtin!(
+    /// Convert a `-0000` timezone to `+0000`.
+    ReplaceLeadingZeros => ::replace_leading_zeros
+);
+builtin!(
+    /// Replace timezone abbreviations chrono does not know with a numeric offset.
+    ReplaceZone => ::replace_zone
+);
+builtin!(
+    /// Append the default offset to dates that have no timezone.
+    AssumeOffset => ::assume_offset
+);
@@ -0,0 +1,216 @@
+use chrono::{DateTime, Datelike, Offset, TimeZone, Timelike, Utc};
+use std::fmt::Write;
+
+use locale::MONTHS;
+use zone;
+
+/// Writes dates as RFC 2822, for feeds of your own.
+///
+/// Unlike `DateTime::to_rfc2822`, the weekday, the zero padding of the day
+/// and the spelling of the zone can be chosen. Whatever the options, the
+/// output parses back with `parse_from_rfc2822_with_fallback` to the same
+/// instant, for years 1900 to 9999. Fractional seconds are dropped, and
+/// offsets that are not whole minutes, as some historical ones are, are
+/// written in UTC instead.
+///
+/// ```rust
+/// # extern crate chrono; extern crate rfc822_sanitizer;
+/// # use chrono::DateTime;
+/// use rfc822_sanitizer::{parse_from_rfc2822_with_fallback, Rfc2822Formatter};
+///
+/// # fn main() {
+/// let dt = parse_from_rfc2822_with_fallback("Mon, 31 July 2017 16:00:00 -0000").unwrap();
+/// assert_eq!(
+///     Rfc2822Formatter::new().format(&dt),
+///     "Mon, 31 Jul 2017 16:00:00 +0000"
+/// );
+///
+/// let formatter = Rfc2822Formatter::new()
+///     .weekday(false)
+///     .pad_day(false)
+///     .gmt(true);
+/// let dt = DateTime::parse_from_rfc2822("6 Aug 2017 09:05:00 GMT").unwrap();
+/// assert_eq!(formatter.format(&dt), "6 Aug 2017 09:05:00 GMT");
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rfc2822Formatter {
+    weekday: bool,
+    pad_day: bool,
+    gmt: bool,
+    unknown_zone: bool,
+}
+
+impl Default for Rfc2822Formatter {
+    fn default() -> Self {
+        Rfc2822Formatter::new()
+    }
+}
+
+impl Rfc2822Formatter {
+    /// Same output as `DateTime::to_rfc2822`: weekday, zero-padded day
+    /// and numeric zone.
+    pub fn new() -> Self {
+        Rfc2822Formatter {
+            weekday: true,
+            pad_day: true,
+            gmt: false,
+            unknown_zone: false,
+        }
+    }
+
+    /// Start with the weekday, as in "Mon, 31 Jul 2017". On by default.
+    pub fn weekday(mut self, weekday: bool) -> Self {
+        self.weekday = weekday;
+        self
+    }
+
+    /// Zero-pad single digit days, as in "06 Aug 2017". On by default.
+    pub fn pad_day(mut self, pad: bool) -> Self {
+        self.pad_day = pad;
+        self
+    }
+
+    /// Write `GMT` instead of `+0000` for dates in UTC. Off by default.
+    pub fn gmt(mut self, gmt: bool) -> Self {
+        self.gmt = gmt;
+        self
+    }
+
+    /// Write every date in UTC with the obsolete `-0000` zone, which
+    /// RFC 2822 uses for dates whose local offset is unknown. Off by default.
+    pub fn unknown_zone(mut self, unknown: bool) -> Self {
+        self.unknown_zone = unknown;
+        self
+    }
+
+    /// Write `dt` as RFC 2822.
+    pub fn format<Tz: TimeZone>(&self, dt: &DateTime<Tz>) -> String {
+        let mut dt = dt.with_timezone(&dt.offset().fix());
+        // RFC 2822 offsets have no seconds.
+        if self.unknown_zone || dt.offset().local_minus_utc() % 60 != 0 {
+            dt = dt.with_timezone(&Utc.fix());
+        }
+
+        let mut s = String::with_capacity(31);
+        if self.weekday {
+            write!(s, "{}, ", dt.weekday()).unwrap();
+        }
+        if self.pad_day {
+            write!(s, "{:02}", dt.day()).unwrap();
+        } else {
+            write!(s, "{}", dt.day()).unwrap();
+        }
+        write!(
+            s,
+            " {} {:04} {:02}:{:02}:{:02} ",
+            MONTHS[dt.month0() as usize],
+            dt.year(),
+            dt.hour(),
+            dt.minute(),
+            // A leap second is written as :60
+            dt.second() + dt.nanosecond() / 1_000_000_000
+        )
+        .unwrap();
+
+        let offset = *dt.offset();
+        if self.unknown_zone {
+            s.push_str("-0000");
+        } else if self.gmt && offset.local_minus_utc() == 0 {
+            s.push_str("GMT");
+        } else {
+            s.push_str(&zone::format_offset(offset));
+        }
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{FixedOffset, NaiveDate};
+    use parse_from_rfc2822_with_fallback;
+
+    #[test]
+    fn test_format() {
+        let dt = DateTime::parse_from_rfc2822("Sun, 06 Aug 2017 09:05:00 -0730").unwrap();
+        let foo = [
+            (Rfc2822Formatter::new(), "Sun, 06 Aug 2017 09:05:00 -0730"),
+            (
+                Rfc2822Formatter::new().weekday(false),
+                "06 Aug 2017 09:05:00 -0730",
+            ),
+            (
+                Rfc2822Formatter::new().pad_day(false),
+                "Sun, 6 Aug 2017 09:05:00 -0730",
+            ),
+            (
+                Rfc2822Formatter::new().gmt(true),
+                "Sun, 06 Aug 2017 09:05:00 -0730",
+            ),
+            (
+                Rfc2822Formatter::new().unknown_zone(true),
+                "Sun, 06 Aug 2017 16:35:00 -0000",
+            ),
+        ];
+
+        foo.iter()
+            .for_each(|&(formatter, good)| assert_eq!(formatter.format(&dt), good));
+
+        let dt = Utc.ymd(2017, 7, 31).and_hms(16, 0, 0);
+        assert_eq!(Rfc2822Formatter::new().format(&dt), dt.to_rfc2822());
+
+        let dt = DateTime::parse_from_rfc2822("Mon, 31 Jul 2017 16:00:00 +0000").unwrap();
+        let dt = dt.with_timezone(&FixedOffset::east(3617));
+        assert_eq!(
+            Rfc2822Formatter::new().format(&dt),
+            "Mon, 31 Jul 2017 16:00:00 +0000"
+        );
+        assert_eq!(
+            Rfc2822Formatter::new().gmt(true).format(&dt),
+            "Mon, 31 Jul 2017 16:00:00 GMT"
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let formatters: Vec<_> = (0..16)
+            .map(|bits| {
+                Rfc2822Formatter::new()
+                    .weekday(bits & 1 != 0)
+                    .pad_day(bits & 2 != 0)
+                    .gmt(bits & 4 != 0)
+                    .unknown_zone(bits & 8 != 0)
+            })
+            .collect();
+        let offsets = (-24..=28).map(|half_hours| FixedOffset::east(half_hours * 1800));
+        let dates = [
+            NaiveDate::from_ymd(1900, 1, 1).and_hms(0, 0, 0),
+            NaiveDate::from_ymd(1969, 12, 31).and_hms(23, 59, 59),
+            NaiveDate::from_ymd(2000, 2, 29).and_hms(12, 30, 0),
+            NaiveDate::from_ymd(2017, 8, 6).and_hms(9, 5, 7),
+            NaiveDate::from_ymd(9999, 12, 31).and_hms(10, 0, 0),
+        ];
+
+        // +01:00:17 is written in UTC.
+        let odd = [
+            FixedOffset::east(5 * 3600 + 45 * 60),
+            FixedOffset::east(3617),
+            FixedOffset::west(3617),
+        ];
+
+        for offset in offsets.chain(odd.iter().cloned()) {
+            for date in &dates {
+                let dt = offset.from_local_datetime(date).unwrap();
+                for formatter in &formatters {
+                    let s = formatter.format(&dt);
+                    let parsed = parse_from_rfc2822_with_fallback(s.as_str()).unwrap();
+                    assert_eq!(parsed, dt, "{}", s);
+                    if !formatter.unknown_zone && offset.local_minus_utc() % 60 == 0 {
+                        assert_eq!(parsed.offset(), dt.offset(), "{}", s);
+                    }
+                }
+            }
+        }
+    }
+}
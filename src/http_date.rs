@@ -0,0 +1,328 @@
+//! HTTP-date parsing, as used by the `Date`, `Last-Modified` and `Expires`
+//! headers.
+//!
+//! [RFC 7231](https://tools.ietf.org/html/rfc7231#section-7.1.1.1) allows
+//! three formats:
+//!
+//! * IMF-fixdate, `Sun, 06 Nov 1994 08:49:37 GMT`
+//! * RFC 850, `Sunday, 06-Nov-94 08:49:37 GMT`
+//! * asctime, `Sun Nov  6 08:49:37 1994`
+//!
+//! ```rust
+//! # extern crate chrono; extern crate rfc822_sanitizer;
+//! # use chrono::{TimeZone, Utc};
+//! use rfc822_sanitizer::http_date::parse_http_date;
+//!
+//! # fn main() {
+//! let expected = Utc.ymd(1994, 11, 6).and_hms(8, 49, 37);
+//! assert_eq!(parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT"), Ok(expected));
+//! assert_eq!(parse_http_date("Sunday, 06-Nov-94 08:49:37 GMT"), Ok(expected));
+//! assert_eq!(parse_http_date("Sun Nov  6 08:49:37 1994"), Ok(expected));
+//! # }
+//! ```
+//!
+//! Cookies have their own, much more tolerant, algorithm for the `Expires`
+//! attribute, see `parse_cookie_date`.
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use regex::Regex;
+
+use locale::MONTHS;
+use {Error, Sanitizer};
+
+lazy_static! {
+    /// HTTP-dates are always in GMT, even when the zone is missing.
+    static ref HTTP_SANITIZER: Sanitizer = Sanitizer::new().assume_utc();
+}
+
+/// Parse an HTTP-date in any of the three formats of RFC 7231.
+///
+/// Dates that are none of them go through the same sanitization as
+/// `parse_from_rfc2822_with_fallback`, with UTC assumed when the zone is
+/// missing.
+///
+/// Two digit RFC 850 years that would be more than 50 years in the future
+/// are taken to be in the past century, as RFC 7231 says.
+pub fn parse_http_date(s: &str) -> Result<DateTime<Utc>, Error> {
+    let s = s.trim();
+    let strict = DateTime::parse_from_rfc2822(s)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+        .or_else(|| parse_rfc850(s, Utc::now()))
+        .or_else(|| parse_asctime(s));
+
+    match strict {
+        Some(dt) => Ok(dt),
+        None => HTTP_SANITIZER.parse(s).map(|dt| dt.with_timezone(&Utc)),
+    }
+}
+
+/// Parse `Sunday, 06-Nov-94 08:49:37 GMT`, with `now` deciding the century.
+fn parse_rfc850(s: &str, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    lazy_static! {
+        /// weekday, day, month, year, time = cap[1], cap[2], cap[3], cap[4], cap[5]
+        static ref RFC850_RGX: Regex = Regex::new(
+            r"^(?i)(Mon|Tues|Wednes|Thurs|Fri|Satur|Sun)day, ([0-9]{2})-([a-z]{3})-([0-9]{2}) ([0-9]{2}:[0-9]{2}:[0-9]{2}) GMT$"
+        )
+        .unwrap();
+    }
+
+    let cap = RFC850_RGX.captures(s)?;
+    let yy: i32 = cap[4].parse().unwrap();
+    let mut year = now.year() - now.year() % 100 + yy;
+    if year > now.year() + 50 {
+        year -= 100;
+    }
+
+    let imf = format!(
+        "{}, {} {} {} {} GMT",
+        &cap[1][..3],
+        &cap[2],
+        &cap[3],
+        year,
+        &cap[5]
+    );
+    DateTime::parse_from_rfc2822(&imf)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Parse `Sun Nov  6 08:49:37 1994`.
+fn parse_asctime(s: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(s, "%a %b %e %H:%M:%S %Y")
+        .ok()
+        .map(|dt| Utc.from_utc_datetime(&dt))
+}
+
+/// Parse the `Expires` attribute of a cookie, following the algorithm of
+/// [RFC 6265 section 5.1.1](https://tools.ietf.org/html/rfc6265#section-5.1.1).
+///
+/// The date is split into tokens on anything but letters, digits and `:`,
+/// and the first token that looks like a time, day, month or year is taken
+/// for it, in that order. Everything else, including the zone, is ignored.
+///
+/// ```rust
+/// # extern crate chrono; extern crate rfc822_sanitizer;
+/// # use chrono::{TimeZone, Utc};
+/// use rfc822_sanitizer::http_date::parse_cookie_date;
+///
+/// # fn main() {
+/// let expected = Utc.ymd(1994, 11, 6).and_hms(8, 49, 37);
+/// assert_eq!(parse_cookie_date("Sun, 06-Nov-1994 08:49:37 GMT"), Some(expected));
+/// assert_eq!(parse_cookie_date("8:49:37 nov 6th 94"), Some(expected));
+/// assert_eq!(parse_cookie_date("Sun, 31-Nov-1994 08:49:37 GMT"), None);
+/// # }
+/// ```
+pub fn parse_cookie_date(s: &str) -> Option<DateTime<Utc>> {
+    let mut time = None;
+    let mut day = None;
+    let mut month = None;
+    let mut year = None;
+
+    for token in s.as_bytes().split(|&b| is_cookie_delimiter(b)) {
+        if token.is_empty() {
+            continue;
+        }
+        if time.is_none() {
+            if let Some(t) = cookie_time(token) {
+                time = Some(t);
+                continue;
+            }
+        }
+        if day.is_none() {
+            if let Some((d, _)) = cookie_digits(token, 1, 2) {
+                day = Some(d);
+                continue;
+            }
+        }
+        if month.is_none() {
+            if let Some(m) = cookie_month(token) {
+                month = Some(m);
+                continue;
+            }
+        }
+        if year.is_none() {
+            if let Some((y, _)) = cookie_digits(token, 2, 4) {
+                year = Some(y);
+                continue;
+            }
+        }
+    }
+
+    let (hour, minute, second) = time?;
+    let year = match year? {
+        y @ 70..=99 => y + 1900,
+        y @ 0..=69 => y + 2000,
+        y => y,
+    };
+    if year < 1601 || hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+
+    NaiveDate::from_ymd_opt(year as i32, month?, day?)
+        .and_then(|d| d.and_hms_opt(hour, minute, second))
+        .map(|dt| Utc.from_utc_datetime(&dt))
+}
+
+/// `delimiter` of RFC 6265: %x09 / %x20-2F / %x3B-40 / %x5B-60 / %x7B-7E
+fn is_cookie_delimiter(b: u8) -> bool {
+    matches!(b, 0x09 | 0x20..=0x2F | 0x3B..=0x40 | 0x5B..=0x60 | 0x7B..=0x7E)
+}
+
+/// `min` to `max` leading digits, not followed by another digit,
+/// and what comes after them.
+fn cookie_digits(token: &[u8], min: usize, max: usize) -> Option<(u32, &[u8])> {
+    let len = token.iter().take_while(|b| b.is_ascii_digit()).count();
+    if len < min || len > max {
+        return None;
+    }
+    let value = token[..len]
+        .iter()
+        .fold(0, |n, &b| n * 10 + u32::from(b - b'0'));
+    Some((value, &token[len..]))
+}
+
+/// `hms-time ( non-digit *OCTET )`, with 1 or 2 digit fields.
+fn cookie_time(token: &[u8]) -> Option<(u32, u32, u32)> {
+    let (hour, rest) = cookie_digits(token, 1, 2)?;
+    let (minute, rest) = cookie_digits(rest.strip_prefix(b":")?, 1, 2)?;
+    let (second, _) = cookie_digits(rest.strip_prefix(b":")?, 1, 2)?;
+    Some((hour, minute, second))
+}
+
+/// A token starting with a month abbreviation, in any case.
+fn cookie_month(token: &[u8]) -> Option<u32> {
+    let prefix = token.get(..3)?;
+    MONTHS
+        .iter()
+        .position(|m| m.as_bytes().eq_ignore_ascii_case(prefix))
+        .map(|i| i as u32 + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_http_date() {
+        let expected = Utc.ymd(1994, 11, 6).and_hms(8, 49, 37);
+        let foo = [
+            "Sun, 06 Nov 1994 08:49:37 GMT",
+            "Sunday, 06-Nov-94 08:49:37 GMT",
+            "Sun Nov  6 08:49:37 1994",
+            "Sun Nov 06 08:49:37 1994",
+            // Lenient
+            " Sun, 06 Nov 1994 08:49:37 GMT ",
+            "SUNDAY, 06-NOV-94 08:49:37 GMT",
+            "Sunday, 06 November 1994 08:49:37 GMT",
+            "Sun, 6 Nov 1994 8:49:37 GMT",
+            "Sun, 06 Nov 1994 08:49:37",
+            "Sun, 06 Nov 1994 08:49:37 UTC",
+        ];
+
+        foo.iter()
+            .for_each(|s| assert_eq!(parse_http_date(s), Ok(expected), "{}", s));
+
+        // The wrong weekday is dropped, like `parse_from_rfc2822_with_fallback` does.
+        assert_eq!(
+            parse_http_date("Mon, 06 Nov 1994 08:49:37 GMT"),
+            Ok(expected)
+        );
+        assert!(parse_http_date("Sunday, 06-Nov-٩٤ 08:49:37 GMT").is_err());
+        assert!(parse_http_date("0").is_err());
+        assert!(parse_http_date("").is_err());
+    }
+
+    #[test]
+    fn test_parse_rfc850() {
+        let now = Utc.ymd(2017, 7, 31).and_hms(16, 0, 0);
+        let foo = [
+            ("Sunday, 06-Nov-94 08:49:37 GMT", Some(1994)),
+            ("Sunday, 06-Nov-67 08:49:37 GMT", Some(2067)),
+            ("Wednesday, 06-Nov-68 08:49:37 GMT", Some(1968)),
+            ("Monday, 06-Nov-17 08:49:37 GMT", Some(2017)),
+            ("Sun, 06-Nov-94 08:49:37 GMT", None),
+            ("Sunday, 06-Nov-94 08:49:37 PST", None),
+            ("Sunday, 06-Nov-٩٤ 08:49:37 GMT", None),
+        ];
+
+        foo.iter().for_each(|&(s, year)| {
+            assert_eq!(parse_rfc850(s, now).map(|dt| dt.year()), year, "{}", s)
+        });
+    }
+
+    #[test]
+    fn test_parse_cookie_date() {
+        let expected = Utc.ymd(1994, 11, 6).and_hms(8, 49, 37);
+        let foo = [
+            "Sun, 06 Nov 1994 08:49:37 GMT",
+            "Sunday, 06-Nov-94 08:49:37 GMT",
+            "Sun Nov  6 08:49:37 1994",
+            "Sun, 06-Nov-1994 08:49:37 GMT",
+            "06 Nov 1994 08:49:37",
+            "8:49:37 6 nov 94",
+            "1994 NOVEMBER 6 8:49:37",
+            "6th Novembre 1994 8:49:37pm",
+            "Sun, 06 Nov 1994 08:49:37 -0800",
+            "Sun,06/Nov/1994+08:49:37.123",
+            "\tSun; 06 Nov 1994 08:49:37 GMT;",
+        ];
+
+        foo.iter()
+            .for_each(|s| assert_eq!(parse_cookie_date(s), Some(expected), "{}", s));
+
+        let foo = [
+            (
+                "Thu, 01 Jan 70 00:00:00 GMT",
+                Utc.ymd(1970, 1, 1).and_hms(0, 0, 0),
+            ),
+            (
+                "Sat, 01 Jan 00 00:00:00 GMT",
+                Utc.ymd(2000, 1, 1).and_hms(0, 0, 0),
+            ),
+            (
+                "Sun, 01 Jan 69 00:00:00 GMT",
+                Utc.ymd(2069, 1, 1).and_hms(0, 0, 0),
+            ),
+            (
+                "Mon, 01 Jan 1601 00:00:00 GMT",
+                Utc.ymd(1601, 1, 1).and_hms(0, 0, 0),
+            ),
+            (
+                "29 Feb 2000 23:59:59",
+                Utc.ymd(2000, 2, 29).and_hms(23, 59, 59),
+            ),
+            ("1 Jan 9999 0:0:0", Utc.ymd(9999, 1, 1).and_hms(0, 0, 0)),
+        ];
+
+        foo.iter()
+            .for_each(|&(s, dt)| assert_eq!(parse_cookie_date(s), Some(dt), "{}", s));
+
+        let foo = [
+            // Missing parts.
+            "",
+            "Sun, 06 Nov 1994",
+            "Sun, Nov 1994 08:49:37 GMT",
+            "Sun, 06 1994 08:49:37 GMT",
+            "Sun, 06 Nov 08:49:37 GMT",
+            // Out of range.
+            "Sun, 32 Nov 1994 08:49:37 GMT",
+            "Sun, 00 Nov 1994 08:49:37 GMT",
+            "Sun, 06 Nov 1600 08:49:37 GMT",
+            "Sun, 06 Nov 1994 24:49:37 GMT",
+            "Sun, 06 Nov 1994 08:60:37 GMT",
+            "Sun, 06 Nov 1994 08:49:60 GMT",
+            // Not a date.
+            "Wed, 31 Apr 1994 08:49:37 GMT",
+            "Tue, 29 Feb 2100 08:49:37 GMT",
+            // Too many digits.
+            "Sun, 006 Nov 1994 08:49:37 GMT",
+            "Sun, 06 Nov 19940 08:49:37 GMT",
+            "Sun, 06 Nov 1994 008:49:37 GMT",
+            "Sun, 06 Nov 1994 08:49:370 GMT",
+        ];
+
+        foo.iter()
+            .for_each(|s| assert_eq!(parse_cookie_date(s), None, "{}", s));
+    }
+}
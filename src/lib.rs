@@ -1,123 +1,835 @@
 #![recursion_limit = "1024"]
-#![cfg_attr(feature = "cargo-clippy", allow(let_and_return))]
 
 extern crate chrono;
 #[macro_use]
 extern crate lazy_static;
 extern crate regex;
 
-use chrono::{DateTime, FixedOffset, ParseResult};
+use chrono::{DateTime, FixedOffset, Weekday};
 use regex::Regex;
 use std::borrow::Cow;
+use std::fmt;
+use std::ops::Range;
+
+mod error;
+mod fallback;
+pub mod fixups;
+mod formatter;
+pub mod http_date;
+pub mod locale;
+mod sanitizer;
+pub mod zone;
+
+pub use error::Error;
+pub use formatter::Rfc2822Formatter;
+pub use sanitizer::{
+    Context, Fixup, Format, FractionPolicy, Parsed, Sanitizer, WeekdayPolicy, YearPolicy,
+};
+
+/// The kind of repair a fixup applied to a date string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FixKind {
+    /// HTML entity was decoded, or CDATA marker removed.
+    DecodeHtml,
+    /// Whitespace was collapsed, or invisible characters removed.
+    NormalizeWhitespace,
+    /// RFC 5322 comment, like "(PDT)", was removed.
+    StripComments,
+    /// Fractional seconds were removed from the time.
+    StripFraction,
+    /// 12-hour time with AM/PM was converted to 24-hour.
+    ConvertMeridiem,
+    /// Single digit hour, minute or second was zero-padded.
+    PadZeros,
+    /// Missing seconds were added to an `HH:MM` time.
+    AddSeconds,
+    /// Weekday name was removed.
+    RemoveWeekday,
+    /// Ordinal suffix was removed from the day.
+    StripOrdinal,
+    /// Month name was replaced with its RFC 2822 abbreviation.
+    ReplaceMonth,
+    /// Month-first date was reordered to day, month, year.
+    ReorderDate,
+    /// Two or three digit year was expanded to four digits.
+    ExpandYear,
+    /// `±HH:MM`, `±HH` or `±H` offset was rewritten as `±HHMM`.
+    NormalizeOffset,
+    /// `-0000` timezone was replaced with `+0000`.
+    ReplaceLeadingZeros,
+    /// Timezone abbreviation was replaced with a numeric offset.
+    ReplaceZone,
+    /// Missing timezone was filled in with the default offset.
+    AssumeOffset,
+    /// Repair made by a user supplied `Fixup`.
+    Custom(&'static str),
+}
+
+impl fmt::Display for FixKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            FixKind::DecodeHtml => "HTML decoding",
+            FixKind::NormalizeWhitespace => "whitespace normalization",
+            FixKind::StripComments => "comment removal",
+            FixKind::StripFraction => "fractional seconds removal",
+            FixKind::ConvertMeridiem => "12-hour clock conversion",
+            FixKind::PadZeros => "zero padding",
+            FixKind::AddSeconds => "seconds insertion",
+            FixKind::RemoveWeekday => "weekday removal",
+            FixKind::StripOrdinal => "ordinal removal",
+            FixKind::ReplaceMonth => "month replacement",
+            FixKind::ReorderDate => "date reordering",
+            FixKind::ExpandYear => "year expansion",
+            FixKind::NormalizeOffset => "offset normalization",
+            FixKind::ReplaceLeadingZeros => "-0000 replacement",
+            FixKind::ReplaceZone => "timezone replacement",
+            FixKind::AssumeOffset => "assumed offset",
+            FixKind::Custom(name) => name,
+        };
+        f.write_str(name)
+    }
+}
+
+/// A single repair applied while sanitizing a date string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fix {
+    /// Which fixup made the repair.
+    pub kind: FixKind,
+    /// Byte range of the replaced text in the input of the sanitizer.
+    ///
+    /// A fix of text that an earlier fix wrote spans all of the input that
+    /// earlier fix replaced, and text inserted from nothing gets an empty span.
+    pub span: Range<usize>,
+    /// The text that was replaced, as the fixup saw it. Earlier fixes may
+    /// have changed it from `input[span]`.
+    pub original: String,
+    /// The text it was replaced with.
+    pub replacement: String,
+}
+
+/// The outcome of `sanitize_with_report` and `Sanitizer::sanitize`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SanitizeReport {
+    /// The normalized date string.
+    pub sanitized: String,
+    /// Every repair that was applied, in the order they were applied.
+    pub fixes: Vec<Fix>,
+    /// The weekday named by the input, if one was removed.
+    pub weekday: Option<Weekday>,
+    /// Ambiguous timezone abbreviation that was given its default meaning.
+    pub ambiguous_zone: Option<String>,
+    /// Timezone named in a comment, as in "-0800 (PDT)", that does not match
+    /// the offset before it.
+    pub zone_mismatch: Option<String>,
+    /// Fractional seconds removed from the time, in nanoseconds.
+    pub nanosecond: Option<u32>,
+}
+
+impl SanitizeReport {
+    /// Returns `true` if no repair was needed.
+    pub fn is_clean(&self) -> bool {
+        self.fixes.is_empty()
+    }
+
+    /// Returns `true` if a repair of the given kind was applied.
+    pub fn applied(&self, kind: FixKind) -> bool {
+        self.fixes.iter().any(|f| f.kind == kind)
+    }
+}
 
 /// Tries to fix common ways date generators misshandle rfc822/rfc2822.
 ///
-/// For more check the source code, Its ~70 lines of code.
+/// Runs the default `Sanitizer` pipeline, see `Sanitizer` to configure it.
 pub fn sanitize_rfc822_like_date<S: Into<String>>(s: S) -> String {
-    let s = s.into();
-    let s = pad_zeros(s);
-    let s = remove_weekday(s);
-    let s = replace_month(s);
-    let s = replace_leading_zeros(s);
+    sanitize_with_report(s).sanitized
+}
+
+/// Same as `sanitize_rfc822_like_date` but also reports which repairs were applied.
+///
+/// ```rust
+/// # extern crate rfc822_sanitizer;
+/// # use rfc822_sanitizer::{sanitize_with_report, FixKind};
+///
+/// # fn main() {
+/// let report = sanitize_with_report("Mon, 31 July 2017 16:00:00 -0000");
+/// assert_eq!(report.sanitized, "31 Jul 2017 16:00:00 +0000");
+///
+/// let kinds: Vec<_> = report.fixes.iter().map(|f| f.kind).collect();
+/// assert_eq!(
+///     kinds,
+///     vec![
+///         FixKind::RemoveWeekday,
+///         FixKind::ReplaceMonth,
+///         FixKind::ReplaceLeadingZeros,
+///     ]
+/// );
+/// # }
+/// ```
+pub fn sanitize_with_report<S: Into<String>>(s: S) -> SanitizeReport {
+    DEFAULT_SANITIZER.sanitize(s)
+}
+
+lazy_static! {
+    static ref DEFAULT_SANITIZER: Sanitizer = Sanitizer::new();
+}
+
+/// Decode HTML entities, like "&nbsp;" or "&#32;", and remove CDATA markers.
+///
+/// Only entities that may show up in a date are known, others are left alone.
+fn decode_html(s: String, cx: &mut Context) -> String {
+    lazy_static! {
+        /// decimal, hexadecimal, named entity = cap[1], cap[2], cap[3]
+        static ref HTML_RGX: Regex = Regex::new(
+            r"<!\[CDATA\[|\]\]>|&(?:#([0-9]{1,7})|#[xX]([0-9A-Fa-f]{1,6})|([A-Za-z]+));"
+        )
+        .unwrap();
+    }
+
+    let edits: Vec<_> = HTML_RGX
+        .captures_iter(&s)
+        .filter_map(|cap| {
+            let decoded = if let Some(m) = cap.get(1) {
+                m.as_str().parse().ok().and_then(std::char::from_u32)
+            } else if let Some(m) = cap.get(2) {
+                u32::from_str_radix(m.as_str(), 16)
+                    .ok()
+                    .and_then(std::char::from_u32)
+            } else if let Some(m) = cap.get(3) {
+                html_entity(m.as_str())
+            } else {
+                // CDATA marker
+                return Some((cap.get(0).unwrap().range(), String::new()));
+            };
+            decoded.map(|c| (cap.get(0).unwrap().range(), c.to_string()))
+        })
+        .collect();
+
+    let mut s = s;
+    cx.replace_all(FixKind::DecodeHtml, &mut s, edits);
+    s
+}
+
+/// The character of a named HTML entity.
+fn html_entity(name: &str) -> Option<char> {
+    let c = match name {
+        "nbsp" => '\u{a0}',
+        "ensp" => '\u{2002}',
+        "emsp" => '\u{2003}',
+        "thinsp" => '\u{2009}',
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "comma" => ',',
+        "period" => '.',
+        "colon" => ':',
+        "plus" => '+',
+        _ => return None,
+    };
+    Some(c)
+}
+
+/// Collapse runs of whitespace into a single space and trim the ends.
+///
+/// Any Unicode whitespace counts, so non-breaking spaces, tabs and
+/// RFC 5322 header folding are unfolded too. BOMs and zero-width
+/// characters are removed.
+fn normalize_whitespace(s: String, cx: &mut Context) -> String {
+    lazy_static! {
+        /// Whitespace and invisible characters.
+        static ref SPACE_RGX: Regex =
+            Regex::new(r"[\s\u{FEFF}\u{200B}-\u{200D}\u{2060}]+").unwrap();
+    }
+
+    let len = s.len();
+    let edits: Vec<_> = SPACE_RGX
+        .find_iter(&s)
+        .filter_map(|m| {
+            let visible = m.as_str().chars().any(char::is_whitespace);
+            let replacement = if !visible || m.start() == 0 || m.end() == len {
+                ""
+            } else {
+                " "
+            };
+            if m.as_str() == replacement {
+                None
+            } else {
+                Some((m.range(), replacement.to_string()))
+            }
+        })
+        .collect();
+
+    let mut s = s;
+    cx.replace_all(FixKind::NormalizeWhitespace, &mut s, edits);
+    s
+}
+
+/// Remove RFC 5322 comments, like the "(PDT)" of "-0700 (PDT)".
+///
+/// Comments can be nested and contain quoted-pairs, as in "(a \) b)".
+/// A timezone name in the comment right after a numeric offset is checked
+/// against that offset, see `Sanitizer::check_zone_comment`. A trailing
+/// comment that is the only timezone of the date, as in "16:00:00 (PDT)",
+/// is replaced with its offset instead.
+fn strip_comments(s: String, cx: &mut Context) -> String {
+    lazy_static! {
+        /// sign, hours, minutes = cap[1], cap[2], cap[3]
+        static ref OFFSET_RGX: Regex = Regex::new(r"([+-])([0-9]{2}):?([0-9]{2})\s*$").unwrap();
+    }
+
+    let comments = match find_comments(&s) {
+        Some(ref comments) if comments.is_empty() => return s,
+        Some(comments) => comments,
+        None => return s,
+    };
+
+    // Only blanks and other comments after the comment at `i`.
+    let trailing = |i: usize| {
+        let mut pos = comments[i].end;
+        for span in &comments[i + 1..] {
+            if !s[pos..span.start].trim().is_empty() {
+                return false;
+            }
+            pos = span.end;
+        }
+        s[pos..].trim().is_empty()
+    };
+
+    // The comment standing in for the timezone, and the offset it names.
+    let mut zone: Option<(usize, String)> = None;
+    for (i, span) in comments.iter().enumerate() {
+        let name = s[span.start + 1..span.end - 1].trim();
+        let offset = OFFSET_RGX.captures(&s[..span.start]).map(|cap| {
+            let secs = cap[2].parse::<i32>().unwrap() * 3600 + cap[3].parse::<i32>().unwrap() * 60;
+            if &cap[1] == "-" {
+                -secs
+            } else {
+                secs
+            }
+        });
+        let resolved = cx.resolver().resolve(name, cx.zones());
+        let named = resolved.as_ref().map(|r| r.offset.local_minus_utc());
+        if let (Some(offset), Some(named)) = (offset, named) {
+            if offset != named {
+                cx.set_zone_mismatch(name);
+            }
+        }
+
+        let zone_before = match s[..span.start].split_whitespace().last() {
+            Some(w) => {
+                w.starts_with(&['+', '-'][..])
+                    || zone::rfc2822_offset(w).is_some()
+                    || cx.resolver().resolve(w, cx.zones()).is_some()
+            }
+            None => false,
+        };
+        match resolved {
+            Some(resolved) if zone.is_none() && !zone_before && trailing(i) => {
+                if resolved.by_default {
+                    cx.set_ambiguous_zone(name);
+                }
+                zone = Some((span.start, zone::format_offset(resolved.offset)));
+            }
+            _ => {}
+        }
+    }
+
+    // Take the blanks around each comment along with it,
+    // merging comments only separated by blanks.
+    let len = s.len();
+    let mut spans: Vec<Range<usize>> = Vec::new();
+    for span in comments {
+        let start = s[..span.start].trim_end().len();
+        let end = len - s[span.end..].trim_start().len();
+        match spans.last_mut() {
+            Some(last) if last.end >= start => last.end = end,
+            _ => spans.push(start..end),
+        }
+    }
+    let edits: Vec<_> = spans
+        .into_iter()
+        .map(|span| {
+            let lead = if span.start == 0 { "" } else { " " };
+            let trail = if span.end == len { "" } else { " " };
+            let replacement = match zone {
+                Some((start, ref offset)) if span.contains(&start) => {
+                    format!("{}{}{}", lead, offset, trail)
+                }
+                _ if lead.is_empty() || trail.is_empty() => String::new(),
+                _ => " ".to_string(),
+            };
+            (span, replacement)
+        })
+        .collect();
+
+    let mut s = s;
+    cx.replace_all(FixKind::StripComments, &mut s, edits);
     s
 }
 
-/// Pad HH:MM:SS with exta zeros if needed.
-fn pad_zeros(s: String) -> String {
+/// Spans of the outermost comments of `s`, `None` if the parentheses do not balance.
+fn find_comments(s: &str) -> Option<Vec<Range<usize>>> {
+    let mut comments = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    let mut chars = s.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' if depth > 0 => {
+                chars.next();
+            }
+            '(' => {
+                if depth == 0 {
+                    start = i;
+                }
+                depth += 1;
+            }
+            ')' if depth == 0 => return None,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    comments.push(start..i + 1);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    if depth == 0 {
+        Some(comments)
+    } else {
+        None
+    }
+}
+
+/// Remove fractional seconds, like the ".123" of "16:00:00.123".
+///
+/// They are recorded in nanoseconds, see `FractionPolicy`. Digits past
+/// nanoseconds are dropped.
+fn strip_fraction(s: String, cx: &mut Context) -> String {
+    lazy_static! {
+        /// fraction, its digits = cap[1], cap[2]
+        static ref FRACTION_RGX: Regex =
+            Regex::new(r"\b[0-9]{1,2}:[0-9]{1,2}:[0-9]{1,2}([.,]([0-9]+))\b").unwrap();
+    }
+
+    let (span, nanosecond) = match FRACTION_RGX.captures(&s) {
+        Some(cap) => {
+            let digits: String = cap[2].chars().chain("00000000".chars()).take(9).collect();
+            (cap.get(1).unwrap().range(), digits.parse().unwrap())
+        }
+        None => return s,
+    };
+
+    let mut s = s;
+    cx.set_nanosecond(nanosecond);
+    cx.replace(FixKind::StripFraction, &mut s, span, "");
+    s
+}
+
+/// Convert 12-hour times followed by AM or PM to 24-hour HH:MM:SS.
+///
+/// Times that can not be 12-hour, like "13:00 PM", are left alone
+/// so the date fails to parse.
+fn convert_meridiem(s: String, cx: &mut Context) -> String {
+    lazy_static! {
+        /// hours, minutes, seconds, meridiem = cap[1], cap[2], cap[3], cap[4]
+        static ref MERIDIEM_RGX: Regex =
+            Regex::new(r"\b([0-9]{1,2}):([0-9]{1,2})(?::([0-9]{1,2}))?\s*([AaPp])\.?[Mm]\b\.?")
+                .unwrap();
+    }
+
+    let (span, tm) = match MERIDIEM_RGX.captures(&s) {
+        Some(cap) => {
+            let hours: u32 = cap[1].parse().unwrap();
+            let minutes: u32 = cap[2].parse().unwrap();
+            let seconds: u32 = cap.get(3).map_or(0, |m| m.as_str().parse().unwrap());
+            if hours == 0 || hours > 12 || minutes > 59 || seconds > 60 {
+                return s;
+            }
+
+            let pm = cap[4].eq_ignore_ascii_case("p");
+            let hours = match (hours, pm) {
+                (12, false) => 0,
+                (12, true) => 12,
+                (h, false) => h,
+                (h, true) => h + 12,
+            };
+            let tm = format!("{:02}:{:02}:{:02}", hours, minutes, seconds);
+            (cap.get(0).unwrap().range(), tm)
+        }
+        None => return s,
+    };
+
+    let mut s = s;
+    cx.replace(FixKind::ConvertMeridiem, &mut s, span, &tm);
+    s
+}
+
+/// Pad HH:MM:SS and HH:MM with exta zeros if needed.
+fn pad_zeros(s: String, cx: &mut Context) -> String {
     lazy_static! {
         /// If it matchers a pattern of 2:2:2, return.
         static ref OK_RGX: Regex = Regex::new(r"(\d{2}):(\d{2}):(\d{2})").unwrap();
 
         /// hours, minutes, seconds = cap[1], cap[2], cap[3]
-        static ref RE_RGX: Regex = Regex::new(r"(\d{1,2}):(\d{1,2}):(\d{1,2})").unwrap();
+        static ref RE_RGX: Regex = Regex::new(r"(\d{1,2}):(\d{1,2})(?::(\d{1,2}))?").unwrap();
     }
 
     if OK_RGX.is_match(&s) {
         return s;
     }
 
-    if let Some(cap) = RE_RGX.captures(&s) {
-        let mut tm = String::with_capacity(2 + 1 + 2 + 1 + 2 + 1);
-        cap.iter().skip(1).filter_map(|m| m).for_each(|mtch| {
-            let m_str = mtch.as_str();
-            if m_str.len() == 1 {
-                tm.push('0');
+    let (span, tm) = match RE_RGX.captures(&s) {
+        Some(cap) => {
+            let mut tm = String::with_capacity(2 + 1 + 2 + 1 + 2 + 1);
+            cap.iter().skip(1).flatten().for_each(|mtch| {
+                let m_str = mtch.as_str();
+                if m_str.len() == 1 {
+                    tm.push('0');
+                }
+                tm.push_str(m_str);
+                tm.push(':');
+            });
+            tm.pop(); // Pop leftover last separator (at no penalty, since we only allocate once
+                      // either way)
+
+            let m = cap.get(0).unwrap();
+            if m.as_str() == tm {
+                return s;
             }
-            tm.push_str(m_str);
-            tm.push(':');
-        });
-        tm.pop(); // Pop leftover last separator (at no penalty, since we only allocate once
-                  // either way)
+            (m.range(), tm)
+        }
+        None => return s,
+    };
 
-        return s.replace(&cap[0], &tm);
+    let mut s = s;
+    cx.replace(FixKind::PadZeros, &mut s, span, &tm);
+    s
+}
+
+/// Append `:00` to HH:MM times that lack seconds.
+fn add_seconds(s: String, cx: &mut Context) -> String {
+    lazy_static! {
+        /// minutes end, seconds = cap[1], cap[2]
+        static ref TIME_RGX: Regex = Regex::new(r"(?:^|\s)\d{2}:(\d{2})(:\d{2})?\b").unwrap();
     }
 
+    let end = match TIME_RGX.captures(&s) {
+        Some(ref cap) if cap.get(2).is_none() => cap.get(1).unwrap().end(),
+        _ => return s,
+    };
+
+    let mut s = s;
+    cx.replace(FixKind::AddSeconds, &mut s, end..end, ":00");
     s
 }
 
 /// Weekday name is not required for rfc2822
-fn remove_weekday(s: String) -> String {
-    static WEEKDAYS: &[&str] = &[
-        "Mon,",
-        "Tue,",
-        "Wed,",
-        "Thu,",
-        "Fri,",
-        "Sat,",
-        "Sun,",
-        "Monday,",
-        "Tuesday,",
-        "Wednesday,",
-        "Thursday,",
-        "Friday,",
-        "Saturday,",
-        "Sunday,",
-    ];
-
-    WEEKDAYS
-        .iter()
-        .find(|&w| s.starts_with(w))
-        .map(|w| s[w.len()..].trim().to_string())
-        .unwrap_or(s)
+///
+/// Weekdays are removed wherever they appear, along with the punctuation
+/// right after them. Names that are also months, like "mar" (mardi, martes),
+/// are only removed from the start of the date and when followed by a
+/// comma. Two letter names, like "so" (Sonntag), are only removed from the
+/// start of the date.
+fn remove_weekday(s: String, cx: &mut Context) -> String {
+    lazy_static! {
+        /// A word, the punctuation right after it and the blanks that follow.
+        static ref WEEKDAY_RGX: Regex =
+            Regex::new(r"(\p{L}+(?:-\p{L}+)*)((?:\s*[.,])*)\s*").unwrap();
+    }
+
+    let leading = s.len() - s.trim_start().len();
+    let mut weekday = None;
+    let mut edits: Vec<(Range<usize>, String)> = Vec::new();
+    for cap in WEEKDAY_RGX.captures_iter(&s) {
+        let m = cap.get(0).unwrap();
+        let day = match locale::find_weekday(&cap[1], cx.locales()) {
+            Some(day) => day,
+            None => continue,
+        };
+        let first = m.start() == leading;
+        // Two letter names like "so" or "do" are too easily something else.
+        if !first && cap[1].chars().count() < 3 {
+            continue;
+        }
+        // "mar." is March as often as it is Tuesday, only a comma settles it.
+        let punctuated = first && cap[2].contains(',');
+        if !punctuated && locale::find_month(&cap[1], cx.locales()).is_some() {
+            continue;
+        }
+
+        let mut start = if first { 0 } else { m.start() };
+        // Back to back weekdays make a single edit, as in "Mon Tue".
+        if edits.last().map(|edit| edit.0.end) == Some(start) {
+            start = edits.pop().unwrap().0.start;
+        }
+        if m.end() == s.len() {
+            start = s[..start].trim_end().len();
+        }
+        weekday = weekday.or(Some(day));
+        edits.push((start..m.end(), String::new()));
+    }
+
+    let weekday = match weekday {
+        Some(weekday) => weekday,
+        None => return s,
+    };
+
+    // Trim the tail as well, unless the last weekday already did.
+    let end = s.trim_end().len();
+    if end < s.len() && edits[edits.len() - 1].0.end < s.len() {
+        edits.push((end..s.len(), String::new()));
+    }
+
+    let mut s = s;
+    cx.replace_all(FixKind::RemoveWeekday, &mut s, edits);
+    cx.set_weekday(weekday);
+    s
 }
 
-/// Replace long month names with 3 letter Abr as specified in RFC2822.
-fn replace_month(s: String) -> String {
-    static MONTHS: &[(&str, &str)] = &[
-        ("January", "Jan"),
-        ("February", "Feb"),
-        ("March", "Mar"),
-        ("April ", "Apr"),
-        ("May", "May"),
-        ("June", "Jun"),
-        ("July", "Jul"),
-        ("August", "Aug"),
-        ("September", "Sep"),
-        ("October", "Oct"),
-        ("November", "Nov"),
-        ("December", "Dec"),
-    ];
-
-    MONTHS
-        .iter()
-        .find(|&&(k, _)| s.contains(k))
-        .map(|&(k, v)| s.replace(k, v))
-        .unwrap_or(s)
+/// Remove the "st", "nd", "rd" or "th" suffix of the day, as in "31st".
+///
+/// Only suffixes of numbers from 1 to 31 are removed.
+fn strip_ordinal(s: String, cx: &mut Context) -> String {
+    lazy_static! {
+        /// day, suffix = cap[1], cap[2]
+        static ref ORDINAL_RGX: Regex = Regex::new(r"(?i)\b([0-9]{1,2})(st|nd|rd|th)\b").unwrap();
+    }
+
+    let span = match ORDINAL_RGX.captures_iter(&s).find(|cap| {
+        let day: u32 = cap[1].parse().unwrap();
+        (1..=31).contains(&day)
+    }) {
+        Some(cap) => cap.get(2).unwrap().range(),
+        None => return s,
+    };
+
+    let mut s = s;
+    cx.replace(FixKind::StripOrdinal, &mut s, span, "");
+    s
+}
+
+/// Replace month names, in any case, with the 3 letter Abr as specified in RFC2822.
+fn replace_month(s: String, cx: &mut Context) -> String {
+    lazy_static! {
+        /// A word, with an optional trailing dot.
+        static ref WORD_RGX: Regex = Regex::new(r"\p{L}+(?:-\p{L}+)*\.?").unwrap();
+    }
+
+    let edits: Vec<_> = WORD_RGX
+        .find_iter(&s)
+        .filter_map(|m| {
+            let month = locale::find_month(m.as_str(), cx.locales())?;
+            let abbr = locale::MONTHS[month as usize - 1];
+            if m.as_str() == abbr {
+                None
+            } else {
+                Some((m.range(), abbr.to_string()))
+            }
+        })
+        .collect();
+
+    let mut s = s;
+    cx.replace_all(FixKind::ReplaceMonth, &mut s, edits);
+    s
+}
+
+/// Rewrite month-first dates, like "Jul 31, 2017" or asctime's
+/// "Jul 31 16:00:00 2017", as "31 Jul 2017".
+fn reorder_date(s: String, cx: &mut Context) -> String {
+    lazy_static! {
+        /// month, day, year, comma after the year = cap[1], cap[2], cap[3], cap[4]
+        static ref MDY_RGX: Regex = Regex::new(&format!(
+            r"\b({})\s+(\d{{1,2}})(?:\s*,\s*|\s+)(\d{{2,4}})(,?)(?:\s|$)",
+            locale::MONTHS.join("|")
+        ))
+        .unwrap();
+
+        /// month, day, time, year = cap[1], cap[2], cap[3], cap[4]
+        static ref ASCTIME_RGX: Regex = Regex::new(&format!(
+            r"\b({})\s+(\d{{1,2}})\s+(\d{{2}}:\d{{2}}(?::\d{{2}})?)\s+(\d{{4}})\b",
+            locale::MONTHS.join("|")
+        ))
+        .unwrap();
+    }
+
+    // A number right before the month means the day already comes first.
+    let month_first = |start: usize| {
+        !s[..start]
+            .trim_end()
+            .ends_with(|c: char| c.is_ascii_digit())
+    };
+
+    let (span, date) = if let Some(cap) = MDY_RGX
+        .captures(&s)
+        .filter(|cap| month_first(cap.get(0).unwrap().start()))
+    {
+        let start = cap.get(1).unwrap().start();
+        let end = cap.get(4).unwrap().end();
+        (start..end, format!("{} {} {}", &cap[2], &cap[1], &cap[3]))
+    } else if let Some(cap) = ASCTIME_RGX
+        .captures(&s)
+        .filter(|cap| month_first(cap.get(0).unwrap().start()))
+    {
+        let span = cap.get(0).unwrap().range();
+        let date = format!("{} {} {} {}", &cap[2], &cap[1], &cap[4], &cap[3]);
+        (span, date)
+    } else {
+        return s;
+    };
+
+    let mut s = s;
+    cx.replace(FixKind::ReorderDate, &mut s, span, &date);
+    s
+}
+
+/// Expand two and three digit years following the month, see `YearPolicy`.
+fn expand_year(s: String, cx: &mut Context) -> String {
+    lazy_static! {
+        /// year = cap[1]
+        static ref YEAR_RGX: Regex = Regex::new(&format!(
+            r"\b[0-9]{{1,2}}\s+(?:{})\s+([0-9]{{2,3}})(?:\s|$)",
+            locale::MONTHS.join("|")
+        ))
+        .unwrap();
+    }
+
+    let (span, year) = match YEAR_RGX.captures(&s) {
+        Some(cap) => {
+            let m = cap.get(1).unwrap();
+            let year = cx
+                .year_policy()
+                .expand(m.as_str().parse().unwrap(), m.as_str().len());
+            (m.range(), year.to_string())
+        }
+        None => return s,
+    };
+
+    let mut s = s;
+    cx.replace(FixKind::ExpandYear, &mut s, span, &year);
+    s
+}
+
+/// Convert ±HH:MM, ±H:MM, ±HH and ±H offsets to ±HHMM.
+///
+/// Other lengths, like "+053", are left alone rather than guessed at.
+fn normalize_offset(s: String, cx: &mut Context) -> String {
+    lazy_static! {
+        /// sign, offset = cap[1], cap[2]
+        static ref OFFSET_RGX: Regex =
+            Regex::new(r"\s([+-])(\d{1,2}:\d{2}|\d{4}|\d{1,2})\s*$").unwrap();
+    }
+
+    let (span, offset) = match OFFSET_RGX.captures(&s) {
+        Some(cap) => {
+            let m = cap.get(0).unwrap();
+            let start = m.start() + m.as_str().find(&cap[1]).unwrap();
+            let end = start + s[start..].trim_end().len();
+            let (hours, minutes) = match cap[2].find(':') {
+                Some(colon) => (&cap[2][..colon], &cap[2][colon + 1..]),
+                None if cap[2].len() == 4 => cap[2].split_at(2),
+                None => (&cap[2], "00"),
+            };
+            let offset = format!("{}{:0>2}{}", &cap[1], hours, minutes);
+            if s[start..end] == offset {
+                return s;
+            }
+            (start..end, offset)
+        }
+        None => return s,
+    };
+
+    let mut s = s;
+    cx.replace(FixKind::NormalizeOffset, &mut s, span, &offset);
+    s
 }
 
 /// Convert -0000 to +0000.
 /// See [#102](https://github.com/chronotope/chrono/issues/102)
-fn replace_leading_zeros(s: String) -> String {
+fn replace_leading_zeros(s: String, cx: &mut Context) -> String {
     if s.ends_with("-0000") {
-        format!("{}+0000", &s[..s.len() - 5])
+        let mut s = s;
+        let len = s.len();
+        cx.replace(FixKind::ReplaceLeadingZeros, &mut s, len - 5..len, "+0000");
+        s
     } else {
         s
     }
 }
 
+/// Replace timezone abbreviations chrono does not know with a numeric offset.
+///
+/// Only the last word is considered, and names chrono already maps to the
+/// same offset are left alone. Ambiguous names are resolved by the
+/// `ZoneAbbrevResolver` of the sanitizer.
+fn replace_zone(s: String, cx: &mut Context) -> String {
+    lazy_static! {
+        /// The last word, if it follows a blank.
+        static ref ZONE_RGX: Regex = Regex::new(r"\s([A-Za-z]{1,5})\s*$").unwrap();
+    }
+
+    let (span, abbr, resolved) = match ZONE_RGX.captures(&s) {
+        Some(cap) => {
+            let m = cap.get(1).unwrap();
+            match cx.resolver().resolve(m.as_str(), cx.zones()) {
+                Some(resolved) => (m.range(), m.as_str().to_string(), resolved),
+                None => return s,
+            }
+        }
+        None => return s,
+    };
+
+    if resolved.by_default {
+        cx.set_ambiguous_zone(&abbr);
+    }
+    if zone::rfc2822_offset(&abbr) == Some(resolved.offset) {
+        return s;
+    }
+
+    let mut s = s;
+    cx.replace(
+        FixKind::ReplaceZone,
+        &mut s,
+        span,
+        &zone::format_offset(resolved.offset),
+    );
+    s
+}
+
+/// Append the default offset of the sanitizer to dates ending with the time.
+///
+/// Does nothing unless a default offset was configured.
+fn assume_offset(s: String, cx: &mut Context) -> String {
+    lazy_static! {
+        /// A time with nothing after it.
+        static ref TIME_RGX: Regex = Regex::new(r"\d{2}:\d{2}(?::\d{2})?\s*$").unwrap();
+    }
+
+    let offset = match cx.default_offset() {
+        Some(offset) if TIME_RGX.is_match(&s) => offset,
+        _ => return s,
+    };
+
+    let mut s = s;
+    let len = s.trim_end().len();
+    cx.replace(
+        FixKind::AssumeOffset,
+        &mut s,
+        len..len,
+        &format!(" {}", zone::format_offset(offset)),
+    );
+    s
+}
+
 /// Calls `DateTime::parse_from_rfc2822()`. If it succedes returns,
 /// Else it calls `sanitize_rfc822_like_date` and retries.
 ///
+/// Dates that are not RFC 2822 at all, like "2017-07-31T16:00:00Z", are
+/// then tried as RFC 3339 and ISO 8601. `Sanitizer::parse_with_report`
+/// tells which `Format` matched.
+///
 /// Basic usage:
 ///
 /// ```rust
@@ -128,30 +840,23 @@ fn replace_leading_zeros(s: String) -> String {
 /// # fn main() {
 /// let bad_input = parse_from_rfc2822_with_fallback("Thu, 05 Aug 2016 06:00:00 -0400");
 /// let correct_result = DateTime::parse_from_rfc2822("Fri, 05 Aug 2016 06:00:00 -0400");
-/// assert_eq!(bad_input, correct_result);
+/// assert_eq!(bad_input.ok(), correct_result.ok());
 /// # }
 /// ```
+///
+/// If every attempt fails, the returned `Error` carries both RFC 2822 errors
+/// along with the sanitized string that was tried.
 pub fn parse_from_rfc2822_with_fallback<'s, S: Into<Cow<'s, str>>>(
     s: S,
-) -> ParseResult<DateTime<FixedOffset>> {
-    let s = s.into();
-    let date = DateTime::parse_from_rfc2822(&s);
-    match date {
-        Ok(_) => date,
-        Err(err) => {
-            let san = sanitize_rfc822_like_date(s);
-            if let Ok(dt) = DateTime::parse_from_rfc2822(&san) {
-                return Ok(dt);
-            }
-            Err(err)
-        }
-    }
+) -> Result<DateTime<FixedOffset>, Error> {
+    DEFAULT_SANITIZER.parse(s)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use chrono::DateTime;
+    use chrono::NaiveDate;
 
     #[test]
     fn test_invalid_dates() {
@@ -416,8 +1121,53 @@ mod tests {
 
         dates.iter().for_each(|&(bad, good)| {
             assert_eq!(
-                parse_from_rfc2822_with_fallback(bad),
-                DateTime::parse_from_rfc2822(good)
+                parse_from_rfc2822_with_fallback(bad).unwrap(),
+                DateTime::parse_from_rfc2822(good).unwrap()
+            )
+        });
+    }
+
+    #[test]
+    fn test_localized_dates() {
+        let dates = [
+            (
+                "mar., 12 sept. 2017 10:00:00 +0200",
+                "12 Sep 2017 10:00:00 +0200",
+            ),
+            (
+                "Mi, 20 Sep 2017 10:00:00 +0200",
+                "20 Sep 2017 10:00:00 +0200",
+            ),
+            (
+                "mié., 20 sept. 2017 10:00:00 +0200",
+                "20 Sep 2017 10:00:00 +0200",
+            ),
+            (
+                "jeudi, 3 août 2017 06:00:00 +0200",
+                "3 Aug 2017 06:00:00 +0200",
+            ),
+            (
+                "Dienstag, 14 März 2017 06:00:00 +0100",
+                "14 Mar 2017 06:00:00 +0100",
+            ),
+            (
+                "lunedì, 3 luglio 2017 06:00:00 +0200",
+                "3 Jul 2017 06:00:00 +0200",
+            ),
+            (
+                "ma, 3 juli 2017 06:00:00 +0200",
+                "3 Jul 2017 06:00:00 +0200",
+            ),
+            (
+                "segunda-feira, 3 julho 2017 06:00:00 -0300",
+                "3 Jul 2017 06:00:00 -0300",
+            ),
+        ];
+
+        dates.iter().for_each(|&(bad, good)| {
+            assert_eq!(
+                parse_from_rfc2822_with_fallback(bad).unwrap(),
+                DateTime::parse_from_rfc2822(good).unwrap()
             )
         });
     }
@@ -688,6 +1438,99 @@ mod tests {
             .for_each(|&(bad, good)| assert_eq!(sanitize_rfc822_like_date(bad.to_string()), good));
     }
 
+    #[test]
+    fn test_sanitize_with_report() {
+        let report = sanitize_with_report("Thu, 30 Aug 2017 1:30:00 -0000");
+        assert_eq!(report.sanitized, "30 Aug 2017 01:30:00 +0000");
+        assert_eq!(
+            report.fixes,
+            vec![
+                Fix {
+                    kind: FixKind::PadZeros,
+                    span: 17..24,
+                    original: "1:30:00".to_string(),
+                    replacement: "01:30:00".to_string(),
+                },
+                Fix {
+                    kind: FixKind::RemoveWeekday,
+                    span: 0..5,
+                    original: "Thu, ".to_string(),
+                    replacement: "".to_string(),
+                },
+                Fix {
+                    kind: FixKind::ReplaceLeadingZeros,
+                    span: 25..30,
+                    original: "-0000".to_string(),
+                    replacement: "+0000".to_string(),
+                },
+            ]
+        );
+
+        // Spans point into the input, whatever the stages before did.
+        let input = "Mon, 31 July 2017 9:5 -00:00";
+        let report = sanitize_with_report(input);
+        assert_eq!(report.sanitized, "31 Jul 2017 09:05:00 +0000");
+        let spans: Vec<_> = report
+            .fixes
+            .iter()
+            .map(|f| (f.kind, &input[f.span.clone()]))
+            .collect();
+        assert_eq!(
+            spans,
+            vec![
+                (FixKind::PadZeros, "9:5"),
+                (FixKind::AddSeconds, ""),
+                (FixKind::RemoveWeekday, "Mon, "),
+                (FixKind::ReplaceMonth, "July"),
+                (FixKind::NormalizeOffset, "-00:00"),
+                (FixKind::ReplaceLeadingZeros, "-00:00"),
+            ]
+        );
+        let month = report
+            .fixes
+            .iter()
+            .find(|f| f.kind == FixKind::ReplaceMonth);
+        assert_eq!(month.unwrap().span, 8..12);
+
+        let report = sanitize_with_report("Mon, 31 July 2017 16:00:00 PDT");
+        assert!(report.applied(FixKind::ReplaceMonth));
+        assert!(!report.applied(FixKind::PadZeros));
+
+        let report = sanitize_with_report("31 Jul 2017 16:00:00 PDT");
+        assert!(report.is_clean());
+        assert_eq!(report.sanitized, "31 Jul 2017 16:00:00 PDT");
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        let dates = [
+            (
+                "MON, 31 JUL 2017 16:00:00 +0000",
+                "31 Jul 2017 16:00:00 +0000",
+            ),
+            (
+                "MONDAY, 31 JULY 2017 16:00:00 PDT",
+                "31 Jul 2017 16:00:00 PDT",
+            ),
+            (
+                "monday, 31 july 2017 16:00:00 pdt",
+                "31 Jul 2017 16:00:00 pdt",
+            ),
+            (
+                "Mon, 31 jUL 2017 16:00:00 +0000",
+                "31 Jul 2017 16:00:00 +0000",
+            ),
+            (
+                "WED, 20 SEPT. 2017 10:00:00 -0000",
+                "20 Sep 2017 10:00:00 +0000",
+            ),
+        ];
+
+        dates
+            .iter()
+            .for_each(|&(bad, good)| assert_eq!(sanitize_rfc822_like_date(bad), good));
+    }
+
     #[test]
     fn test_remove_weekday() {
         let foo = vec![
@@ -778,202 +1621,593 @@ mod tests {
                 "19 Apr 2017 10:00:00 -0000",
             ),
             (
-                "Wed, 12 Apr 2017 10:00:00 -0000",
-                "12 Apr 2017 10:00:00 -0000",
+                "Wed, 12 Apr 2017 10:00:00 -0000",
+                "12 Apr 2017 10:00:00 -0000",
+            ),
+            (
+                "Wed, 05 Apr 2017 10:00:00 -0000",
+                "05 Apr 2017 10:00:00 -0000",
+            ),
+            (
+                "Wed, 29 Mar 2017 10:00:00 -0000",
+                "29 Mar 2017 10:00:00 -0000",
+            ),
+            (
+                "Wed, 22 Mar 2017 10:00:00 -0000",
+                "22 Mar 2017 10:00:00 -0000",
+            ),
+            (
+                "Wed, 15 Mar 2017 10:00:00 -0000",
+                "15 Mar 2017 10:00:00 -0000",
+            ),
+            (
+                "Wed, 08 Mar 2017 11:00:00 -0000",
+                "08 Mar 2017 11:00:00 -0000",
+            ),
+            (
+                "Wed, 01 Mar 2017 11:00:00 -0000",
+                "01 Mar 2017 11:00:00 -0000",
+            ),
+            (
+                "Wed, 22 Feb 2017 11:00:00 -0000",
+                "22 Feb 2017 11:00:00 -0000",
+            ),
+            (
+                "Wed, 15 Feb 2017 11:00:00 -0000",
+                "15 Feb 2017 11:00:00 -0000",
+            ),
+            (
+                "Wed, 08 Feb 2017 11:00:00 -0000",
+                "08 Feb 2017 11:00:00 -0000",
+            ),
+            (
+                "Wed, 01 Feb 2017 11:00:00 -0000",
+                "01 Feb 2017 11:00:00 -0000",
+            ),
+            (
+                "Wed, 25 Jan 2017 11:00:00 -0000",
+                "25 Jan 2017 11:00:00 -0000",
+            ),
+            (
+                "Fri, 13 Jan 2017 18:38:00 -0000",
+                "13 Jan 2017 18:38:00 -0000",
+            ),
+            (
+                "Wed, 20 Sep 2017 03:30:00 -0000",
+                "20 Sep 2017 03:30:00 -0000",
+            ),
+            (
+                "Wed, 13 Sep 2017 03:15:00 -0000",
+                "13 Sep 2017 03:15:00 -0000",
+            ),
+            (
+                "Wed, 06 Sep 2017 03:15:00 -0000",
+                "06 Sep 2017 03:15:00 -0000",
+            ),
+            (
+                "Wed, 30 Aug 2017 03:15:00 -0000",
+                "30 Aug 2017 03:15:00 -0000",
+            ),
+            (
+                "Wed, 23 Aug 2017 03:15:00 -0000",
+                "23 Aug 2017 03:15:00 -0000",
+            ),
+            (
+                "Wed, 16 Aug 2017 03:15:00 -0000",
+                "16 Aug 2017 03:15:00 -0000",
+            ),
+            (
+                "Wed, 09 Aug 2017 03:15:00 -0000",
+                "09 Aug 2017 03:15:00 -0000",
+            ),
+            (
+                "Wed, 02 Aug 2017 03:00:00 -0000",
+                "02 Aug 2017 03:00:00 -0000",
+            ),
+            (
+                "Tue, 11 Jul 2017 17:14:45 -0000",
+                "11 Jul 2017 17:14:45 -0000",
+            ),
+            (
+                "Thu, 03 August 2017 06:00:00 -0400",
+                "03 August 2017 06:00:00 -0400",
+            ),
+            (
+                "Thu, 27 July 2017 06:00:00 -0400",
+                "27 July 2017 06:00:00 -0400",
+            ),
+            (
+                "Thu, 20 July 2017 06:00:00 -0400",
+                "20 July 2017 06:00:00 -0400",
+            ),
+            (
+                "Thu, 13 July 2017 06:00:00 -0400",
+                "13 July 2017 06:00:00 -0400",
+            ),
+            (
+                "Thu, 06 July 2017 06:00:00 -0400",
+                "06 July 2017 06:00:00 -0400",
+            ),
+            (
+                "Thu, 28 June 2017 06:00:00 -0400",
+                "28 June 2017 06:00:00 -0400",
+            ),
+            (
+                "Thu, 17 Jul 2013 06:00:03 -0400",
+                "17 Jul 2013 06:00:03 -0400",
+            ),
+            (
+                "Thu, 02 Apr 2014 06:00:03 -0400",
+                "02 Apr 2014 06:00:03 -0400",
+            ),
+            (
+                "Wed, 14 Jan 2016 06:00:03 -0400",
+                "14 Jan 2016 06:00:03 -0400",
+            ),
+            (
+                "Thu, 22 June 2017 06:00:00 -0400",
+                "22 June 2017 06:00:00 -0400",
+            ),
+            (
+                "Thu, 15 June 2017 06:00:00 -0400",
+                "15 June 2017 06:00:00 -0400",
+            ),
+            (
+                "Thu, 7 June 2017 06:00:00 -0400",
+                "7 June 2017 06:00:00 -0400",
+            ),
+            (
+                "Thu, 1 June 2017 06:00:00 -0400",
+                "1 June 2017 06:00:00 -0400",
+            ),
+            (
+                "Thu, 23 Dec 2015 06:00:03 -0400",
+                "23 Dec 2015 06:00:03 -0400",
+            ),
+            (
+                "Thu, 14 Feb 2014 06:00:03 -0400",
+                "14 Feb 2014 06:00:03 -0400",
+            ),
+            (
+                "Thu, 04 Dec 2013 06:00:03 -0400",
+                "04 Dec 2013 06:00:03 -0400",
+            ),
+            (
+                "Thu, 20 Dec 2016 06:00:00 -0400",
+                "20 Dec 2016 06:00:00 -0400",
+            ),
+            (
+                "Thu, 23 Nov 2016 06:00:00 -0400",
+                "23 Nov 2016 06:00:00 -0400",
+            ),
+            (
+                "Thu, 05 Aug 2016 06:00:00 -0400",
+                "05 Aug 2016 06:00:00 -0400",
+            ),
+            (
+                "Fri, 09 Jun 2016 12:00:00 -0400",
+                "09 Jun 2016 12:00:00 -0400",
+            ),
+            (
+                "Thu, 10 May 2017 06:00:00 -0400",
+                "10 May 2017 06:00:00 -0400",
+            ),
+            (
+                "Thu, 22 Feb 2017 06:00:00 -0400",
+                "22 Feb 2017 06:00:00 -0400",
+            ),
+            (
+                "Thu, 15 Feb 2017 06:00:00 -0400",
+                "15 Feb 2017 06:00:00 -0400",
+            ),
+        ];
+
+        foo.iter().for_each(|&(bad, good)| {
+            assert_eq!(remove_weekday(bad.to_string(), &mut Context::new()), good)
+        });
+    }
+
+    #[test]
+    fn test_remove_weekday_anywhere() {
+        let foo = [
+            (
+                "Mon 31 Jul 2017 16:00:00 +0000",
+                "31 Jul 2017 16:00:00 +0000",
+            ),
+            ("Monday 31 July 2017", "31 July 2017"),
+            (
+                "31 Jul 2017 Mon 16:00:00 +0000",
+                "31 Jul 2017 16:00:00 +0000",
+            ),
+            (
+                "31 Jul 2017 16:00:00 +0000 Mon.",
+                "31 Jul 2017 16:00:00 +0000",
+            ),
+            (
+                "  Mon. 31 Jul 2017 16:00:00 +0000 ",
+                "31 Jul 2017 16:00:00 +0000",
+            ),
+            (
+                "Mon , 31 Jul 2017 16:00:00 +0000",
+                "31 Jul 2017 16:00:00 +0000",
+            ),
+            (
+                "mar., 12 sept. 2017 10:00:00 +0200",
+                "12 sept. 2017 10:00:00 +0200",
+            ),
+            // "mar" is also a month, so only drop it when it looks like a weekday.
+            ("Mar 12 2017 10:00:00 +0200", "Mar 12 2017 10:00:00 +0200"),
+            ("Mar. 12, 2017 10:00:00 GMT", "Mar. 12, 2017 10:00:00 GMT"),
+            ("12 mar. 2017 10:00:00 +0200", "12 mar. 2017 10:00:00 +0200"),
+            ("31 Jul 2017 16:00:00 PDT", "31 Jul 2017 16:00:00 PDT"),
+            ("31 Jul 2017 16:00:00 SO", "31 Jul 2017 16:00:00 SO"),
+            (
+                "31 Jul 2017 do 16:00:00 +0000",
+                "31 Jul 2017 do 16:00:00 +0000",
+            ),
+            (
+                "So, 30 Jul 2017 16:00:00 +0200",
+                "30 Jul 2017 16:00:00 +0200",
+            ),
+            // Back to back weekdays, the last one at the end.
+            ("Mon Tue", ""),
+            ("31 Jul 2017 16:00 Mon Tue", "31 Jul 2017 16:00"),
+        ];
+
+        foo.iter().for_each(|&(bad, good)| {
+            assert_eq!(remove_weekday(bad.to_string(), &mut Context::new()), good)
+        });
+    }
+
+    #[test]
+    fn test_pad_zeros() {
+        // Would be nice If we had more test cases,
+        // If you stumble(d) upon any online please consider opening a Pullrequest.
+        let foo = [
+            (
+                "Thu, 30 Aug 2017 1:30:00 PDT",
+                "Thu, 30 Aug 2017 01:30:00 PDT",
             ),
+            ("Thu, 30 Aug 2017 9:5 PDT", "Thu, 30 Aug 2017 09:05 PDT"),
+            ("Thu, 30 Aug 2017 16:00 PDT", "Thu, 30 Aug 2017 16:00 PDT"),
             (
-                "Wed, 05 Apr 2017 10:00:00 -0000",
-                "05 Apr 2017 10:00:00 -0000",
+                "Thu, 30 Aug 2017 16:00 +5:30",
+                "Thu, 30 Aug 2017 16:00 +5:30",
             ),
+        ];
+
+        foo.iter().for_each(|&(bad, good)| {
+            assert_eq!(pad_zeros(bad.to_string(), &mut Context::new()), good)
+        });
+    }
+
+    #[test]
+    fn test_decode_html() {
+        let foo = [
             (
-                "Wed, 29 Mar 2017 10:00:00 -0000",
-                "29 Mar 2017 10:00:00 -0000",
+                "Mon,&nbsp;31&#32;Jul 2017 16:00:00 &#x2B;0000",
+                "Mon,\u{a0}31 Jul 2017 16:00:00 +0000",
             ),
             (
-                "Wed, 22 Mar 2017 10:00:00 -0000",
-                "22 Mar 2017 10:00:00 -0000",
+                "<![CDATA[ Mon, 31 Jul 2017 16:00:00 +0000 ]]>",
+                " Mon, 31 Jul 2017 16:00:00 +0000 ",
             ),
             (
-                "Wed, 15 Mar 2017 10:00:00 -0000",
-                "15 Mar 2017 10:00:00 -0000",
+                "<![CDATA[Mon, 31 Jul 2017 16:00:00 &#45;0700]]>",
+                "Mon, 31 Jul 2017 16:00:00 -0700",
             ),
             (
-                "Wed, 08 Mar 2017 11:00:00 -0000",
-                "08 Mar 2017 11:00:00 -0000",
+                "Mon, 31 Jul 2017 16:00:00 +0000 &bogus; &#xFFFFFF;",
+                "Mon, 31 Jul 2017 16:00:00 +0000 &bogus; &#xFFFFFF;",
             ),
             (
-                "Wed, 01 Mar 2017 11:00:00 -0000",
-                "01 Mar 2017 11:00:00 -0000",
+                "Mon, 31 Jul 2017 16:00:00 +0000",
+                "Mon, 31 Jul 2017 16:00:00 +0000",
             ),
+        ];
+
+        foo.iter().for_each(|&(bad, good)| {
+            assert_eq!(decode_html(bad.to_string(), &mut Context::new()), good)
+        });
+
+        // Opt-in, the default pipeline leaves entities alone.
+        let input = "<![CDATA[ Mon,&nbsp;31 Jul 2017 16:00:00 +0000 ]]>";
+        assert!(parse_from_rfc2822_with_fallback(input).is_err());
+
+        let sanitizer = Sanitizer::new().before(FixKind::NormalizeWhitespace, fixups::DecodeHtml);
+        assert_eq!(
+            sanitizer.parse(input).unwrap(),
+            DateTime::parse_from_rfc2822("31 Jul 2017 16:00:00 +0000").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_normalize_whitespace() {
+        let foo = [
             (
-                "Wed, 22 Feb 2017 11:00:00 -0000",
-                "22 Feb 2017 11:00:00 -0000",
+                "Mon,\u{a0}31 Jul 2017\t16:00:00 +0000",
+                "Mon, 31 Jul 2017 16:00:00 +0000",
             ),
             (
-                "Wed, 15 Feb 2017 11:00:00 -0000",
-                "15 Feb 2017 11:00:00 -0000",
+                "Mon, 31 Jul 2017\r\n 16:00:00 +0000",
+                "Mon, 31 Jul 2017 16:00:00 +0000",
             ),
             (
-                "Wed, 08 Feb 2017 11:00:00 -0000",
-                "08 Feb 2017 11:00:00 -0000",
+                "Mon,  31  Jul   2017 16:00:00 +0000",
+                "Mon, 31 Jul 2017 16:00:00 +0000",
             ),
             (
-                "Wed, 01 Feb 2017 11:00:00 -0000",
-                "01 Feb 2017 11:00:00 -0000",
+                "\u{feff}Mon, 31 Jul 2017 16:00:00 +0000",
+                "Mon, 31 Jul 2017 16:00:00 +0000",
             ),
             (
-                "Wed, 25 Jan 2017 11:00:00 -0000",
-                "25 Jan 2017 11:00:00 -0000",
+                "Mon, 31 J\u{200b}ul 2017 16:00:00 +0000\u{200d}",
+                "Mon, 31 Jul 2017 16:00:00 +0000",
             ),
             (
-                "Fri, 13 Jan 2017 18:38:00 -0000",
-                "13 Jan 2017 18:38:00 -0000",
+                " \tMon, 31 Jul 2017 16:00:00 +0000\r\n",
+                "Mon, 31 Jul 2017 16:00:00 +0000",
             ),
             (
-                "Wed, 20 Sep 2017 03:30:00 -0000",
-                "20 Sep 2017 03:30:00 -0000",
+                "Mon, 31\u{202f}\u{2009}Jul 2017 16:00:00 +0000",
+                "Mon, 31 Jul 2017 16:00:00 +0000",
             ),
             (
-                "Wed, 13 Sep 2017 03:15:00 -0000",
-                "13 Sep 2017 03:15:00 -0000",
+                "Mon, 31 Jul 2017 16:00:00 +0000",
+                "Mon, 31 Jul 2017 16:00:00 +0000",
             ),
+        ];
+
+        foo.iter().for_each(|&(bad, good)| {
+            assert_eq!(
+                normalize_whitespace(bad.to_string(), &mut Context::new()),
+                good
+            )
+        });
+
+        assert_eq!(
+            parse_from_rfc2822_with_fallback(
+                "\u{feff}Mon,\u{a0}31\u{a0}Jul 2017\r\n\t16:00:00 +0000"
+            )
+            .unwrap(),
+            DateTime::parse_from_rfc2822("31 Jul 2017 16:00:00 +0000").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_strip_comments() {
+        let foo = [
             (
-                "Wed, 06 Sep 2017 03:15:00 -0000",
-                "06 Sep 2017 03:15:00 -0000",
+                "Mon, 31 Jul 2017 16:00:00 -0700 (PDT)",
+                "Mon, 31 Jul 2017 16:00:00 -0700",
             ),
             (
-                "Wed, 30 Aug 2017 03:15:00 -0000",
-                "30 Aug 2017 03:15:00 -0000",
+                "Mon, 31 Jul 2017 (a (nested) comment) 16:00:00 -0700",
+                "Mon, 31 Jul 2017 16:00:00 -0700",
             ),
             (
-                "Wed, 23 Aug 2017 03:15:00 -0000",
-                "23 Aug 2017 03:15:00 -0000",
+                "Mon, 31 Jul(month)2017 16:00:00 -0700",
+                "Mon, 31 Jul 2017 16:00:00 -0700",
             ),
             (
-                "Wed, 16 Aug 2017 03:15:00 -0000",
-                "16 Aug 2017 03:15:00 -0000",
+                "(sent) Mon, 31 Jul 2017 16:00:00 -0700 (one) (two)",
+                "Mon, 31 Jul 2017 16:00:00 -0700",
             ),
             (
-                "Wed, 09 Aug 2017 03:15:00 -0000",
-                "09 Aug 2017 03:15:00 -0000",
+                "Mon, 31 Jul 2017 16:00:00 -0700 (quoted \\) paren)",
+                "Mon, 31 Jul 2017 16:00:00 -0700",
             ),
+            // The only timezone of the date.
             (
-                "Wed, 02 Aug 2017 03:00:00 -0000",
-                "02 Aug 2017 03:00:00 -0000",
+                "Mon, 31 Jul 2017 16:00:00 (PDT)",
+                "Mon, 31 Jul 2017 16:00:00 -0700",
             ),
             (
-                "Tue, 11 Jul 2017 17:14:45 -0000",
-                "11 Jul 2017 17:14:45 -0000",
+                "Mon, 31 Jul 2017 16:00:00 (IST) (sent)",
+                "Mon, 31 Jul 2017 16:00:00 +0530",
             ),
             (
-                "Thu, 03 August 2017 06:00:00 -0400",
-                "03 August 2017 06:00:00 -0400",
+                "Mon, 31 Jul 2017 16:00:00 PDT (PDT)",
+                "Mon, 31 Jul 2017 16:00:00 PDT",
             ),
             (
-                "Thu, 27 July 2017 06:00:00 -0400",
-                "27 July 2017 06:00:00 -0400",
+                "Mon, 31 Jul 2017 16:00:00 +7 (PDT)",
+                "Mon, 31 Jul 2017 16:00:00 +7",
             ),
             (
-                "Thu, 20 July 2017 06:00:00 -0400",
-                "20 July 2017 06:00:00 -0400",
+                "Mon, 31 Jul 2017 (PDT) 16:00:00",
+                "Mon, 31 Jul 2017 16:00:00",
             ),
+            // Unbalanced, left alone.
             (
-                "Thu, 13 July 2017 06:00:00 -0400",
-                "13 July 2017 06:00:00 -0400",
+                "Mon, 31 Jul 2017 16:00:00 -0700 (PDT",
+                "Mon, 31 Jul 2017 16:00:00 -0700 (PDT",
             ),
             (
-                "Thu, 06 July 2017 06:00:00 -0400",
-                "06 July 2017 06:00:00 -0400",
+                "Mon, 31 Jul 2017 16:00:00 -0700 PDT)",
+                "Mon, 31 Jul 2017 16:00:00 -0700 PDT)",
             ),
+        ];
+
+        foo.iter().for_each(|&(bad, good)| {
+            assert_eq!(strip_comments(bad.to_string(), &mut Context::new()), good)
+        });
+
+        let report = sanitize_with_report("Mon, 31 Jul 2017 16:00:00 -0700 (PDT)");
+        assert_eq!(report.sanitized, "31 Jul 2017 16:00:00 -0700");
+        assert_eq!(report.zone_mismatch, None);
+
+        let report = sanitize_with_report("Mon, 31 Jul 2017 16:00:00 -0800 (PDT)");
+        assert_eq!(report.zone_mismatch, Some("PDT".to_string()));
+
+        let report = sanitize_with_report("Mon, 31 Jul 2017 16:00:00 -0800 (PDT) (sent)");
+        assert_eq!(report.zone_mismatch, Some("PDT".to_string()));
+
+        let report = sanitize_with_report("Mon, 31 Jul 2017 16:00:00 +05:30 (IST)");
+        assert_eq!(report.zone_mismatch, None);
+
+        let report = sanitize_with_report("Mon, 31 Jul 2017 16:00:00 -0800 (not a zone)");
+        assert_eq!(report.zone_mismatch, None);
+
+        let report = sanitize_with_report("Mon, 31 Jul 2017 16:00:00 +٠٧00 (PDT)");
+        assert_eq!(report.zone_mismatch, None);
+
+        let report = sanitize_with_report("Mon, 31 Jul 2017 16:00:00 (CST)");
+        assert_eq!(report.sanitized, "31 Jul 2017 16:00:00 -0600");
+        assert_eq!(report.ambiguous_zone, Some("CST".to_string()));
+        assert_eq!(
+            Sanitizer::new()
+                .assume_utc()
+                .parse("Mon, 31 Jul 2017 16:00:00 (PDT)")
+                .unwrap(),
+            DateTime::parse_from_rfc2822("Mon, 31 Jul 2017 16:00:00 -0700").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_strip_fraction() {
+        let foo = [
             (
-                "Thu, 28 June 2017 06:00:00 -0400",
-                "28 June 2017 06:00:00 -0400",
+                "Mon, 31 Jul 2017 16:00:00.123 +0000",
+                "Mon, 31 Jul 2017 16:00:00 +0000",
+                Some(123_000_000),
             ),
             (
-                "Thu, 17 Jul 2013 06:00:03 -0400",
-                "17 Jul 2013 06:00:03 -0400",
+                "Mon, 31 Jul 2017 16:00:00,5 +0000",
+                "Mon, 31 Jul 2017 16:00:00 +0000",
+                Some(500_000_000),
             ),
             (
-                "Thu, 02 Apr 2014 06:00:03 -0400",
-                "02 Apr 2014 06:00:03 -0400",
+                "Mon, 31 Jul 2017 4:0:0.000001 PM",
+                "Mon, 31 Jul 2017 4:0:0 PM",
+                Some(1_000),
             ),
             (
-                "Wed, 14 Jan 2016 06:00:03 -0400",
-                "14 Jan 2016 06:00:03 -0400",
+                "Mon, 31 Jul 2017 16:00:00.1234567891 +0000",
+                "Mon, 31 Jul 2017 16:00:00 +0000",
+                Some(123_456_789),
             ),
             (
-                "Thu, 22 June 2017 06:00:00 -0400",
-                "22 June 2017 06:00:00 -0400",
+                "Mon, 31 Jul 2017 16:00:00 +0000",
+                "Mon, 31 Jul 2017 16:00:00 +0000",
+                None,
             ),
             (
-                "Thu, 15 June 2017 06:00:00 -0400",
-                "15 June 2017 06:00:00 -0400",
+                "Mon, 31 Jul 2017 16:00:00.١٢ +0000",
+                "Mon, 31 Jul 2017 16:00:00.١٢ +0000",
+                None,
             ),
+        ];
+
+        foo.iter().for_each(|&(bad, good, nanosecond)| {
+            let mut cx = Context::new();
+            assert_eq!(strip_fraction(bad.to_string(), &mut cx), good);
+            assert_eq!(cx.nanosecond(), nanosecond);
+        });
+
+        let report = sanitize_with_report("Mon, 31 Jul 2017 16:00:00.123 +0000");
+        assert_eq!(report.sanitized, "31 Jul 2017 16:00:00 +0000");
+        assert_eq!(report.nanosecond, Some(123_000_000));
+    }
+
+    #[test]
+    fn test_convert_meridiem() {
+        let foo = [
             (
-                "Thu, 7 June 2017 06:00:00 -0400",
-                "7 June 2017 06:00:00 -0400",
+                "Mon, 31 Jul 2017 4:00:00 PM EST",
+                "Mon, 31 Jul 2017 16:00:00 EST",
             ),
             (
-                "Thu, 1 June 2017 06:00:00 -0400",
-                "1 June 2017 06:00:00 -0400",
+                "Mon, 31 Jul 2017 4:00pm EST",
+                "Mon, 31 Jul 2017 16:00:00 EST",
             ),
             (
-                "Thu, 23 Dec 2015 06:00:03 -0400",
-                "23 Dec 2015 06:00:03 -0400",
+                "Mon, 31 Jul 2017 4:00 p.m. EST",
+                "Mon, 31 Jul 2017 16:00:00 EST",
             ),
             (
-                "Thu, 14 Feb 2014 06:00:03 -0400",
-                "14 Feb 2014 06:00:03 -0400",
+                "Mon, 31 Jul 2017 9:05:30 a.m. +0000",
+                "Mon, 31 Jul 2017 09:05:30 +0000",
             ),
+            ("Mon, 31 Jul 2017 11:5 Am", "Mon, 31 Jul 2017 11:05:00"),
             (
-                "Thu, 04 Dec 2013 06:00:03 -0400",
-                "04 Dec 2013 06:00:03 -0400",
+                "Mon, 31 Jul 2017 12:00:00 AM GMT",
+                "Mon, 31 Jul 2017 00:00:00 GMT",
             ),
             (
-                "Thu, 20 Dec 2016 06:00:00 -0400",
-                "20 Dec 2016 06:00:00 -0400",
+                "Mon, 31 Jul 2017 12:30:00 PM GMT",
+                "Mon, 31 Jul 2017 12:30:00 GMT",
             ),
+            // Not a 12-hour time.
             (
-                "Thu, 23 Nov 2016 06:00:00 -0400",
-                "23 Nov 2016 06:00:00 -0400",
+                "Mon, 31 Jul 2017 13:00 PM GMT",
+                "Mon, 31 Jul 2017 13:00 PM GMT",
             ),
             (
-                "Thu, 05 Aug 2016 06:00:00 -0400",
-                "05 Aug 2016 06:00:00 -0400",
+                "Mon, 31 Jul 2017 0:00 AM GMT",
+                "Mon, 31 Jul 2017 0:00 AM GMT",
             ),
             (
-                "Fri, 09 Jun 2016 12:00:00 -0400",
-                "09 Jun 2016 12:00:00 -0400",
+                "Mon, 31 Jul 2017 4:60 PM GMT",
+                "Mon, 31 Jul 2017 4:60 PM GMT",
             ),
+            // Only ASCII digits.
             (
-                "Thu, 10 May 2017 06:00:00 -0400",
-                "10 May 2017 06:00:00 -0400",
+                "Mon, 31 Jul 2017 ٤:00 PM GMT",
+                "Mon, 31 Jul 2017 ٤:00 PM GMT",
             ),
+            // Not a meridiem.
             (
-                "Thu, 22 Feb 2017 06:00:00 -0400",
-                "22 Feb 2017 06:00:00 -0400",
+                "Mon, 31 Jul 2017 4:00:00 AMT",
+                "Mon, 31 Jul 2017 4:00:00 AMT",
             ),
             (
-                "Thu, 15 Feb 2017 06:00:00 -0400",
-                "15 Feb 2017 06:00:00 -0400",
+                "Mon, 31 Jul 2017 16:00:00 PDT",
+                "Mon, 31 Jul 2017 16:00:00 PDT",
             ),
         ];
 
-        foo.iter()
-            .for_each(|&(bad, good)| assert_eq!(remove_weekday(bad.to_string()), good));
+        foo.iter().for_each(|&(bad, good)| {
+            assert_eq!(convert_meridiem(bad.to_string(), &mut Context::new()), good)
+        });
+
+        assert_eq!(
+            parse_from_rfc2822_with_fallback("Mon, 31 Jul 2017 4:00:00 PM EST").unwrap(),
+            DateTime::parse_from_rfc2822("31 Jul 2017 16:00:00 EST").unwrap()
+        );
+        assert!(parse_from_rfc2822_with_fallback("Mon, 31 Jul 2017 13:00 PM EST").is_err());
     }
 
     #[test]
-    fn test_pad_zeros() {
-        // Would be nice If we had more test cases,
-        // If you stumble(d) upon any online please consider opening a Pullrequest.
-        let foo = vec![(
-            "Thu, 30 Aug 2017 1:30:00 PDT",
-            "Thu, 30 Aug 2017 01:30:00 PDT",
-        )];
+    fn test_add_seconds() {
+        let foo = [
+            (
+                "Mon, 31 Jul 2017 16:00 +0000",
+                "Mon, 31 Jul 2017 16:00:00 +0000",
+            ),
+            (
+                "Mon, 31 Jul 2017 16:00 +05:30",
+                "Mon, 31 Jul 2017 16:00:00 +05:30",
+            ),
+            ("Mon, 31 Jul 2017 16:00", "Mon, 31 Jul 2017 16:00:00"),
+            (
+                "Mon, 31 Jul 2017 16:00:00 +0000",
+                "Mon, 31 Jul 2017 16:00:00 +0000",
+            ),
+            ("Mon, 31 Jul 2017 +05:30", "Mon, 31 Jul 2017 +05:30"),
+        ];
 
-        foo.iter()
-            .for_each(|&(bad, good)| assert_eq!(pad_zeros(bad.to_string()), good));
+        foo.iter().for_each(|&(bad, good)| {
+            assert_eq!(add_seconds(bad.to_string(), &mut Context::new()), good)
+        });
+
+        let report = sanitize_with_report("Mon, 31 Jul 2017 9:5 +0000");
+        assert_eq!(report.sanitized, "31 Jul 2017 09:05:00 +0000");
+        assert!(report.applied(FixKind::AddSeconds));
+        assert_eq!(
+            parse_from_rfc2822_with_fallback("Mon, 31 Jul 2017 9:5 +0000").unwrap(),
+            DateTime::parse_from_rfc2822("31 Jul 2017 09:05:00 +0000").unwrap()
+        );
     }
 
     #[test]
@@ -1525,8 +2759,272 @@ mod tests {
             ),
         ];
 
+        foo.iter().for_each(|&(bad, good)| {
+            assert_eq!(replace_month(bad.to_string(), &mut Context::new()), good)
+        });
+    }
+
+    #[test]
+    fn test_replace_zone() {
+        let foo = [
+            ("31 Jul 2017 16:00:00 CET", "31 Jul 2017 16:00:00 +0100"),
+            ("31 Jul 2017 16:00:00 CEST", "31 Jul 2017 16:00:00 +0200"),
+            ("31 Jul 2017 16:00:00 bst", "31 Jul 2017 16:00:00 +0100"),
+            ("31 Jul 2017 16:00:00 IST", "31 Jul 2017 16:00:00 +0530"),
+            ("31 Jul 2017 16:00:00 AEST", "31 Jul 2017 16:00:00 +1000"),
+            ("31 Jul 2017 16:00:00 JST", "31 Jul 2017 16:00:00 +0900"),
+            ("31 Jul 2017 16:00:00 MSK", "31 Jul 2017 16:00:00 +0300"),
+            ("31 Jul 2017 16:00:00 NZDT", "31 Jul 2017 16:00:00 +1300"),
+            ("31 Jul 2017 16:00:00 SGT", "31 Jul 2017 16:00:00 +0800"),
+            ("31 Jul 2017 16:00:00 UTC", "31 Jul 2017 16:00:00 +0000"),
+            ("31 Jul 2017 16:00:00 NST", "31 Jul 2017 16:00:00 -0330"),
+            // chrono already knows these.
+            ("31 Jul 2017 16:00:00 PDT", "31 Jul 2017 16:00:00 PDT"),
+            ("31 Jul 2017 16:00:00 GMT", "31 Jul 2017 16:00:00 GMT"),
+            ("31 Jul 2017 16:00:00 FOO", "31 Jul 2017 16:00:00 FOO"),
+            ("31 Jul 2017 16:00:00 +0100", "31 Jul 2017 16:00:00 +0100"),
+        ];
+
+        foo.iter().for_each(|&(bad, good)| {
+            assert_eq!(replace_zone(bad.to_string(), &mut Context::new()), good)
+        });
+
+        let report = sanitize_with_report("31 Jul 2017 16:00:00 IST");
+        assert_eq!(report.sanitized, "31 Jul 2017 16:00:00 +0530");
+        assert_eq!(report.ambiguous_zone, Some("IST".to_string()));
+
+        let report = sanitize_with_report("31 Jul 2017 16:00:00 CST");
+        assert_eq!(report.sanitized, "31 Jul 2017 16:00:00 CST");
+        assert_eq!(report.ambiguous_zone, Some("CST".to_string()));
+
+        let report = sanitize_with_report("31 Jul 2017 16:00:00 CET");
+        assert_eq!(report.ambiguous_zone, None);
+
+        assert_eq!(
+            parse_from_rfc2822_with_fallback("Mon, 31 July 2017 16:00:00 CEST").unwrap(),
+            DateTime::parse_from_rfc2822("31 Jul 2017 16:00:00 +0200").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_assume_offset() {
+        let sanitizer = Sanitizer::new().default_offset(FixedOffset::west(7 * 3600));
+        let foo = [
+            ("Mon, 31 Jul 2017 16:00:00", "31 Jul 2017 16:00:00 -0700"),
+            ("Mon, 31 Jul 2017 16:00:00 ", "31 Jul 2017 16:00:00 -0700"),
+            ("31 Jul 2017 9:5", "31 Jul 2017 09:05:00 -0700"),
+            ("31 Jul 2017 16:00:00 +0200", "31 Jul 2017 16:00:00 +0200"),
+            ("31 Jul 2017 16:00:00 CEST", "31 Jul 2017 16:00:00 +0200"),
+            ("31 Jul 2017 16:00:00 GMT", "31 Jul 2017 16:00:00 GMT"),
+        ];
+
+        foo.iter()
+            .for_each(|&(bad, good)| assert_eq!(sanitizer.sanitize(bad).sanitized, good));
+
+        let report = sanitizer.sanitize("Mon, 31 Jul 2017 16:00:00");
+        assert!(report.applied(FixKind::AssumeOffset));
+
+        // Without a default offset the zone is left missing.
+        let report = sanitize_with_report("Mon, 31 Jul 2017 16:00:00");
+        assert_eq!(report.sanitized, "31 Jul 2017 16:00:00");
+        assert!(!report.applied(FixKind::AssumeOffset));
+
+        assert_eq!(
+            Sanitizer::new()
+                .assume_utc()
+                .parse("Mon, 31 Jul 2017 16:00:00")
+                .unwrap(),
+            DateTime::parse_from_rfc2822("Mon, 31 Jul 2017 16:00:00 +0000").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_strip_ordinal() {
+        let foo = [
+            ("Monday, 31st July 2017", "Monday, 31 July 2017"),
+            ("Jul 1st, 2017", "Jul 1, 2017"),
+            ("2nd Aug 2017", "2 Aug 2017"),
+            ("3RD Aug 2017", "3 Aug 2017"),
+            ("04th Aug 2017", "04 Aug 2017"),
+            ("11th Aug 2017", "11 Aug 2017"),
+            // Not a day.
+            ("32nd Aug 2017", "32nd Aug 2017"),
+            ("0th Aug 2017", "0th Aug 2017"),
+            ("100th Aug 2017", "100th Aug 2017"),
+            ("٣rd Aug 2017 16:00:00 GMT", "٣rd Aug 2017 16:00:00 GMT"),
+            ("31 Aug 2017 16:00:00 +0000", "31 Aug 2017 16:00:00 +0000"),
+        ];
+
+        foo.iter().for_each(|&(bad, good)| {
+            assert_eq!(strip_ordinal(bad.to_string(), &mut Context::new()), good)
+        });
+
+        assert_eq!(
+            sanitize_rfc822_like_date("Monday, 31st July 2017 16:00:00 GMT"),
+            "31 Jul 2017 16:00:00 GMT"
+        );
+    }
+
+    #[test]
+    fn test_reorder_date() {
+        let foo = [
+            ("Jul 31, 2017 16:00:00 GMT", "31 Jul 2017 16:00:00 GMT"),
+            ("Jul 31 2017 16:00:00 GMT", "31 Jul 2017 16:00:00 GMT"),
+            ("Jul 31, 2017, 16:00:00 GMT", "31 Jul 2017 16:00:00 GMT"),
+            ("Jul 1,2017", "1 Jul 2017"),
+            ("Jul 31 17 16:00:00 GMT", "31 Jul 17 16:00:00 GMT"),
+            ("Jul 31 16:00:00 2017", "31 Jul 2017 16:00:00"),
+            ("Nov  6 08:49:37 1994", "6 Nov 1994 08:49:37"),
+            // Already day first.
+            ("31 Jul 2017 16:00:00 GMT", "31 Jul 2017 16:00:00 GMT"),
+            ("31 Jul 17 16:00:00 GMT", "31 Jul 17 16:00:00 GMT"),
+            ("31 Jul 20 17:00:00 GMT", "31 Jul 20 17:00:00 GMT"),
+        ];
+
+        foo.iter().for_each(|&(bad, good)| {
+            assert_eq!(reorder_date(bad.to_string(), &mut Context::new()), good)
+        });
+
+        let foo = [
+            ("Mon, Jul 31, 2017 16:00:00 GMT", "31 Jul 2017 16:00:00 GMT"),
+            (
+                "Monday, July 31st, 2017 4:00 PM GMT",
+                "31 Jul 2017 16:00:00 GMT",
+            ),
+            ("Sun Nov  6 08:49:37 1994", "6 Nov 1994 08:49:37"),
+        ];
+
         foo.iter()
-            .for_each(|&(bad, good)| assert_eq!(replace_month(bad.to_string()), good));
+            .for_each(|&(bad, good)| assert_eq!(sanitize_rfc822_like_date(bad), good));
+    }
+
+    #[test]
+    fn test_expand_year() {
+        let foo = [
+            ("31 Jul 17 16:00:00 GMT", "31 Jul 2017 16:00:00 GMT"),
+            ("31 Jul 49 16:00:00 GMT", "31 Jul 2049 16:00:00 GMT"),
+            ("31 Jul 50 16:00:00 GMT", "31 Jul 1950 16:00:00 GMT"),
+            ("1 Jan 99", "1 Jan 1999"),
+            ("31 Jul 117 16:00:00 GMT", "31 Jul 2017 16:00:00 GMT"),
+            ("31 Jul 2017 16:00:00 GMT", "31 Jul 2017 16:00:00 GMT"),
+            ("31 Jul 16:00:00 GMT", "31 Jul 16:00:00 GMT"),
+            // Only ASCII digits make a year.
+            ("31 Jul ١٧ 16:00:00 GMT", "31 Jul ١٧ 16:00:00 GMT"),
+        ];
+
+        foo.iter().for_each(|&(bad, good)| {
+            assert_eq!(expand_year(bad.to_string(), &mut Context::new()), good)
+        });
+
+        assert_eq!(
+            sanitize_rfc822_like_date("Mon, 31 July 17 16:00:00 GMT"),
+            "31 Jul 2017 16:00:00 GMT"
+        );
+        assert!(parse_from_rfc2822_with_fallback("31 Jul ١٧ 16:00:00 GMT").is_err());
+    }
+
+    #[test]
+    fn test_expand_year_policy() {
+        let pivot = Sanitizer::new().year_policy(YearPolicy::Pivot(1970));
+        let reference =
+            Sanitizer::new().year_policy(YearPolicy::Reference(NaiveDate::from_ymd(2017, 7, 31)));
+        let foo = [
+            (&pivot, "31 Jul 69 16:00:00 GMT", "31 Jul 2069 16:00:00 GMT"),
+            (&pivot, "31 Jul 70 16:00:00 GMT", "31 Jul 1970 16:00:00 GMT"),
+            (
+                &pivot,
+                "31 Jul 117 16:00:00 GMT",
+                "31 Jul 2017 16:00:00 GMT",
+            ),
+            (
+                &reference,
+                "31 Jul 66 16:00:00 GMT",
+                "31 Jul 2066 16:00:00 GMT",
+            ),
+            (
+                &reference,
+                "31 Jul 67 16:00:00 GMT",
+                "31 Jul 1967 16:00:00 GMT",
+            ),
+            (
+                &reference,
+                "31 Jul 17 16:00:00 GMT",
+                "31 Jul 2017 16:00:00 GMT",
+            ),
+        ];
+
+        foo.iter().for_each(|&(sanitizer, bad, good)| {
+            assert_eq!(sanitizer.sanitize(bad).sanitized, good)
+        });
+    }
+
+    #[test]
+    fn test_normalize_offset() {
+        let foo = [
+            (
+                "Wed, 20 Sep 2017 10:00:00 +05:30",
+                "Wed, 20 Sep 2017 10:00:00 +0530",
+            ),
+            (
+                "Wed, 20 Sep 2017 10:00:00 -07:00",
+                "Wed, 20 Sep 2017 10:00:00 -0700",
+            ),
+            (
+                "Wed, 20 Sep 2017 10:00:00 +00:00",
+                "Wed, 20 Sep 2017 10:00:00 +0000",
+            ),
+            (
+                "Wed, 20 Sep 2017 10:00:00 -00:00",
+                "Wed, 20 Sep 2017 10:00:00 -0000",
+            ),
+            (
+                "Wed, 20 Sep 2017 10:00:00 +02",
+                "Wed, 20 Sep 2017 10:00:00 +0200",
+            ),
+            (
+                "Wed, 20 Sep 2017 10:00:00 -10",
+                "Wed, 20 Sep 2017 10:00:00 -1000",
+            ),
+            (
+                "Wed, 20 Sep 2017 10:00:00 +2",
+                "Wed, 20 Sep 2017 10:00:00 +0200",
+            ),
+            (
+                "Wed, 20 Sep 2017 10:00:00 -7",
+                "Wed, 20 Sep 2017 10:00:00 -0700",
+            ),
+            (
+                "Wed, 20 Sep 2017 10:00:00 +5:45",
+                "Wed, 20 Sep 2017 10:00:00 +0545",
+            ),
+            (
+                "Wed, 20 Sep 2017 10:00:00 -0400",
+                "Wed, 20 Sep 2017 10:00:00 -0400",
+            ),
+            (
+                "Wed, 20 Sep 2017 10:00:00 +0000",
+                "Wed, 20 Sep 2017 10:00:00 +0000",
+            ),
+            (
+                "Wed, 20 Sep 2017 10:00:00 PDT",
+                "Wed, 20 Sep 2017 10:00:00 PDT",
+            ),
+            // Not +00:53, nor +05:03.
+            (
+                "Wed, 20 Sep 2017 10:00:00 +053",
+                "Wed, 20 Sep 2017 10:00:00 +053",
+            ),
+        ];
+
+        foo.iter().for_each(|&(bad, good)| {
+            assert_eq!(normalize_offset(bad.to_string(), &mut Context::new()), good)
+        });
+        assert!(parse_from_rfc2822_with_fallback("Wed, 20 Sep 2017 10:00:00 +053").is_err());
+
+        assert_eq!(
+            sanitize_rfc822_like_date("Wed, 20 Sep 2017 10:00:00 -00:00"),
+            "20 Sep 2017 10:00:00 +0000"
+        );
     }
 
     #[test]
@@ -1806,7 +3304,11 @@ mod tests {
             ),
         ];
 
-        foo.iter()
-            .for_each(|&(bad, good)| assert_eq!(replace_leading_zeros(bad.to_string()), good));
+        foo.iter().for_each(|&(bad, good)| {
+            assert_eq!(
+                replace_leading_zeros(bad.to_string(), &mut Context::new()),
+                good
+            )
+        });
     }
 }
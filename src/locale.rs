@@ -0,0 +1,386 @@
+//! Month and weekday names in languages other than English.
+//!
+//! RFC 2822 only knows the English 3 letter abbreviations, but plenty of
+//! feeds use the names of their own language instead. The tables below map
+//! those names back to the RFC 2822 forms. Names are matched in any case,
+//! with or without a trailing dot and with or without their accents.
+
+use chrono::Weekday;
+
+/// A language whose month and weekday names the sanitizer can translate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    English,
+    French,
+    German,
+    Spanish,
+    Italian,
+    Dutch,
+    Portuguese,
+}
+
+/// RFC 2822 month names, January first.
+pub(crate) static MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+// Every name is lowercase and stripped of accents and dots,
+// see `fold`. Months are January first, weekdays Monday first.
+
+static EN_MONTHS: [&[&str]; 12] = [
+    &["january", "jan"],
+    &["february", "feb"],
+    &["march", "mar"],
+    &["april", "apr"],
+    &["may"],
+    &["june", "jun"],
+    &["july", "jul"],
+    &["august", "aug"],
+    &["september", "sept", "sep"],
+    &["october", "oct"],
+    &["november", "nov"],
+    &["december", "dec"],
+];
+
+static EN_WEEKDAYS: [&[&str]; 7] = [
+    &["monday", "mon"],
+    &["tuesday", "tues", "tue"],
+    &["wednesday", "wed"],
+    &["thursday", "thurs", "thur", "thu"],
+    &["friday", "fri"],
+    &["saturday", "sat"],
+    &["sunday", "sun"],
+];
+
+static FR_MONTHS: [&[&str]; 12] = [
+    &["janvier", "janv", "jan"],
+    &["fevrier", "fevr", "fev"],
+    &["mars", "mar"],
+    &["avril", "avr"],
+    &["mai"],
+    &["juin"],
+    &["juillet", "juil"],
+    &["aout"],
+    &["septembre", "sept"],
+    &["octobre", "oct"],
+    &["novembre", "nov"],
+    &["decembre", "dec"],
+];
+
+static FR_WEEKDAYS: [&[&str]; 7] = [
+    &["lundi", "lun"],
+    &["mardi", "mar"],
+    &["mercredi", "mer"],
+    &["jeudi", "jeu"],
+    &["vendredi", "ven"],
+    &["samedi", "sam"],
+    &["dimanche", "dim"],
+];
+
+static DE_MONTHS: [&[&str]; 12] = [
+    &["januar", "janner", "jan"],
+    &["februar", "feb"],
+    &["marz", "maerz", "mrz"],
+    &["april", "apr"],
+    &["mai"],
+    &["juni", "jun"],
+    &["juli", "jul"],
+    &["august", "aug"],
+    &["september", "sept", "sep"],
+    &["oktober", "okt"],
+    &["november", "nov"],
+    &["dezember", "dez"],
+];
+
+static DE_WEEKDAYS: [&[&str]; 7] = [
+    &["montag", "mo"],
+    &["dienstag", "di"],
+    &["mittwoch", "mi"],
+    &["donnerstag", "do"],
+    &["freitag", "fr"],
+    &["samstag", "sonnabend", "sa"],
+    &["sonntag", "so"],
+];
+
+static ES_MONTHS: [&[&str]; 12] = [
+    &["enero", "ene"],
+    &["febrero", "feb"],
+    &["marzo", "mar"],
+    &["abril", "abr"],
+    &["mayo", "may"],
+    &["junio", "jun"],
+    &["julio", "jul"],
+    &["agosto", "ago"],
+    &["septiembre", "setiembre", "sept", "sep", "set"],
+    &["octubre", "oct"],
+    &["noviembre", "nov"],
+    &["diciembre", "dic"],
+];
+
+static ES_WEEKDAYS: [&[&str]; 7] = [
+    &["lunes", "lun"],
+    &["martes", "mar"],
+    &["miercoles", "mie"],
+    &["jueves", "jue"],
+    &["viernes", "vie"],
+    &["sabado", "sab"],
+    &["domingo", "dom"],
+];
+
+static IT_MONTHS: [&[&str]; 12] = [
+    &["gennaio", "gen"],
+    &["febbraio", "feb"],
+    &["marzo", "mar"],
+    &["aprile", "apr"],
+    &["maggio", "mag"],
+    &["giugno", "giu"],
+    &["luglio", "lug"],
+    &["agosto", "ago"],
+    &["settembre", "set"],
+    &["ottobre", "ott"],
+    &["novembre", "nov"],
+    &["dicembre", "dic"],
+];
+
+static IT_WEEKDAYS: [&[&str]; 7] = [
+    &["lunedi", "lun"],
+    &["martedi", "mar"],
+    &["mercoledi", "mer"],
+    &["giovedi", "gio"],
+    &["venerdi", "ven"],
+    &["sabato", "sab"],
+    &["domenica", "dom"],
+];
+
+static NL_MONTHS: [&[&str]; 12] = [
+    &["januari", "jan"],
+    &["februari", "feb"],
+    &["maart", "mrt", "maa"],
+    &["april", "apr"],
+    &["mei"],
+    &["juni", "jun"],
+    &["juli", "jul"],
+    &["augustus", "aug"],
+    &["september", "sept", "sep"],
+    &["oktober", "okt"],
+    &["november", "nov"],
+    &["december", "dec"],
+];
+
+static NL_WEEKDAYS: [&[&str]; 7] = [
+    &["maandag", "ma"],
+    &["dinsdag", "di"],
+    &["woensdag", "wo"],
+    &["donderdag", "do"],
+    &["vrijdag", "vr"],
+    &["zaterdag", "za"],
+    &["zondag", "zo"],
+];
+
+static PT_MONTHS: [&[&str]; 12] = [
+    &["janeiro", "jan"],
+    &["fevereiro", "fev"],
+    &["marco", "mar"],
+    &["abril", "abr"],
+    &["maio", "mai"],
+    &["junho", "jun"],
+    &["julho", "jul"],
+    &["agosto", "ago"],
+    &["setembro", "set"],
+    &["outubro", "out"],
+    &["novembro", "nov"],
+    &["dezembro", "dez"],
+];
+
+static PT_WEEKDAYS: [&[&str]; 7] = [
+    &["segunda-feira", "segunda", "seg"],
+    &["terca-feira", "terca", "ter"],
+    &["quarta-feira", "quarta", "qua"],
+    &["quinta-feira", "quinta", "qui"],
+    &["sexta-feira", "sexta", "sex"],
+    &["sabado", "sab"],
+    &["domingo", "dom"],
+];
+
+impl Locale {
+    /// Every supported locale, tried in this order when auto-detecting.
+    pub const ALL: &'static [Locale] = &[
+        Locale::English,
+        Locale::French,
+        Locale::German,
+        Locale::Spanish,
+        Locale::Italian,
+        Locale::Dutch,
+        Locale::Portuguese,
+    ];
+
+    fn tables(
+        self,
+    ) -> (
+        &'static [&'static [&'static str]; 12],
+        &'static [&'static [&'static str]; 7],
+    ) {
+        match self {
+            Locale::English => (&EN_MONTHS, &EN_WEEKDAYS),
+            Locale::French => (&FR_MONTHS, &FR_WEEKDAYS),
+            Locale::German => (&DE_MONTHS, &DE_WEEKDAYS),
+            Locale::Spanish => (&ES_MONTHS, &ES_WEEKDAYS),
+            Locale::Italian => (&IT_MONTHS, &IT_WEEKDAYS),
+            Locale::Dutch => (&NL_MONTHS, &NL_WEEKDAYS),
+            Locale::Portuguese => (&PT_MONTHS, &PT_WEEKDAYS),
+        }
+    }
+
+    /// Look up a month name, returning its number (January is 1).
+    ///
+    /// ```rust
+    /// # extern crate rfc822_sanitizer;
+    /// # use rfc822_sanitizer::locale::Locale;
+    /// # fn main() {
+    /// assert_eq!(Locale::French.month("févr."), Some(2));
+    /// assert_eq!(Locale::French.month("Février"), Some(2));
+    /// assert_eq!(Locale::French.month("February"), None);
+    /// # }
+    /// ```
+    pub fn month(self, name: &str) -> Option<u32> {
+        let key = fold(name);
+        self.tables()
+            .0
+            .iter()
+            .position(|names| names.contains(&key.as_str()))
+            .map(|i| i as u32 + 1)
+    }
+
+    /// Look up a weekday name.
+    ///
+    /// ```rust
+    /// # extern crate chrono; extern crate rfc822_sanitizer;
+    /// # use chrono::Weekday;
+    /// # use rfc822_sanitizer::locale::Locale;
+    /// # fn main() {
+    /// assert_eq!(Locale::German.weekday("Mi"), Some(Weekday::Wed));
+    /// assert_eq!(Locale::Spanish.weekday("sáb."), Some(Weekday::Sat));
+    /// # }
+    /// ```
+    pub fn weekday(self, name: &str) -> Option<Weekday> {
+        static DAYS: [Weekday; 7] = [
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+            Weekday::Sun,
+        ];
+
+        let key = fold(name);
+        self.tables()
+            .1
+            .iter()
+            .position(|names| names.contains(&key.as_str()))
+            .map(|i| DAYS[i])
+    }
+}
+
+/// Look up a month name in each of `locales`, in order.
+pub(crate) fn find_month(name: &str, locales: &[Locale]) -> Option<u32> {
+    locales.iter().filter_map(|l| l.month(name)).next()
+}
+
+/// Look up a weekday name in each of `locales`.
+///
+/// Returns `None` if the locales disagree on which day it is.
+pub(crate) fn find_weekday(name: &str, locales: &[Locale]) -> Option<Weekday> {
+    let mut found = locales.iter().filter_map(|l| l.weekday(name));
+    let first = found.next()?;
+    if found.all(|w| w == first) {
+        Some(first)
+    } else {
+        None
+    }
+}
+
+/// Normalize a name for the table lookups.
+///
+/// Drops a trailing dot, strips accents and lowercases it,
+/// so "Février", "FÉVR." and "fevr" all end up in their table form.
+fn fold(name: &str) -> String {
+    let name = name.trim_end_matches('.');
+    let mut key = String::with_capacity(name.len());
+    for c in name.chars().flat_map(char::to_lowercase) {
+        match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => key.push('a'),
+            'ç' => key.push('c'),
+            'è' | 'é' | 'ê' | 'ë' => key.push('e'),
+            'ì' | 'í' | 'î' | 'ï' => key.push('i'),
+            'ñ' => key.push('n'),
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' => key.push('o'),
+            'ù' | 'ú' | 'û' | 'ü' => key.push('u'),
+            'ß' => key.push_str("ss"),
+            _ => key.push(c),
+        }
+    }
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_month() {
+        let foo = [
+            (Locale::English, "July", Some(7)),
+            (Locale::English, "july", Some(7)),
+            (Locale::English, "JULY", Some(7)),
+            (Locale::English, "jUl", Some(7)),
+            (Locale::English, "Sept", Some(9)),
+            (Locale::French, "sept.", Some(9)),
+            (Locale::French, "févr.", Some(2)),
+            (Locale::French, "fevr", Some(2)),
+            (Locale::French, "août", Some(8)),
+            (Locale::French, "Décembre", Some(12)),
+            (Locale::French, "DÉC.", Some(12)),
+            (Locale::German, "März", Some(3)),
+            (Locale::German, "Okt", Some(10)),
+            (Locale::Spanish, "dic.", Some(12)),
+            (Locale::Italian, "giugno", Some(6)),
+            (Locale::Dutch, "mrt", Some(3)),
+            (Locale::Portuguese, "março", Some(3)),
+            (Locale::Portuguese, "out", Some(10)),
+            (Locale::French, "July", None),
+            (Locale::English, "Mon", None),
+        ];
+
+        foo.iter()
+            .for_each(|&(locale, name, month)| assert_eq!(locale.month(name), month));
+    }
+
+    #[test]
+    fn test_weekday() {
+        let foo = [
+            (Locale::English, "Thurs", Some(Weekday::Thu)),
+            (Locale::English, "MONDAY", Some(Weekday::Mon)),
+            (Locale::French, "mar.", Some(Weekday::Tue)),
+            (Locale::German, "Mi", Some(Weekday::Wed)),
+            (Locale::Spanish, "miércoles", Some(Weekday::Wed)),
+            (Locale::Italian, "venerdì", Some(Weekday::Fri)),
+            (Locale::Dutch, "zo", Some(Weekday::Sun)),
+            (Locale::Portuguese, "terça-feira", Some(Weekday::Tue)),
+            (Locale::German, "Mar", None),
+        ];
+
+        foo.iter()
+            .for_each(|&(locale, name, day)| assert_eq!(locale.weekday(name), day));
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find_month("sept.", Locale::ALL), Some(9));
+        assert_eq!(find_month("sept.", &[Locale::German]), Some(9));
+        assert_eq!(find_month("mei", &[Locale::English]), None);
+        assert_eq!(find_weekday("mar", Locale::ALL), Some(Weekday::Tue));
+        assert_eq!(find_weekday("do", Locale::ALL), Some(Weekday::Thu));
+        assert_eq!(find_weekday("do", &[Locale::English]), None);
+    }
+}
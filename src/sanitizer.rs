@@ -0,0 +1,1097 @@
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, Timelike, Weekday};
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::Range;
+
+use fallback;
+use fixups::{
+    AddSeconds, AssumeOffset, ConvertMeridiem, ExpandYear, NormalizeOffset, NormalizeWhitespace,
+    PadZeros, RemoveWeekday, ReorderDate, ReplaceLeadingZeros, ReplaceMonth, ReplaceZone,
+    StripComments, StripFraction, StripOrdinal,
+};
+use locale::Locale;
+use zone::{self, ZoneAbbrevResolver, ZoneTable};
+use {Error, Fix, FixKind, SanitizeReport};
+
+/// A single stage of the sanitizer pipeline.
+///
+/// Implement this to add your own repairs to a `Sanitizer`:
+///
+/// ```rust
+/// # extern crate rfc822_sanitizer;
+/// use rfc822_sanitizer::{Context, FixKind, Fixup, Sanitizer};
+///
+/// struct Utc;
+///
+/// impl Fixup for Utc {
+///     fn kind(&self) -> FixKind {
+///         FixKind::Custom("utc")
+///     }
+///
+///     fn apply(&self, s: String, cx: &mut Context) -> String {
+///         let mut s = s;
+///         if s.ends_with(" UTC") {
+///             let len = s.len();
+///             cx.replace(self.kind(), &mut s, len - 3..len, "GMT");
+///         }
+///         s
+///     }
+/// }
+///
+/// # fn main() {
+/// let sanitizer = Sanitizer::new().with(Utc);
+/// assert!(sanitizer.parse("Mon, 31 July 2017 16:00:00 UTC").is_ok());
+/// # }
+/// ```
+pub trait Fixup: Send + Sync {
+    /// The kind recorded for repairs made by this fixup.
+    fn kind(&self) -> FixKind;
+
+    /// Repair `s`, recording every change through `cx`.
+    fn apply(&self, s: String, cx: &mut Context) -> String;
+}
+
+/// What to do when the weekday named in a date does not match the date.
+///
+/// The headline case is `Thu, 05 Aug 2016`, which was actually a Friday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WeekdayPolicy {
+    /// Keep the date and ignore the weekday. This is the default.
+    #[default]
+    TrustDate,
+    /// Keep the weekday and move the date to the closest day, at most 3 days
+    /// away, that falls on it.
+    TrustWeekday,
+    /// Fail with `Error::WeekdayMismatch`.
+    Reject,
+}
+
+/// How two and three digit years are expanded.
+///
+/// Three digit years always have 1900 added, as RFC 2822 says.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum YearPolicy {
+    /// The RFC 2822 rule: 00 to 49 are 2000 to 2049, 50 to 99 are 1950 to 1999.
+    /// This is the default.
+    #[default]
+    Rfc2822,
+    /// Two digit years are in the hundred years starting at the given year,
+    /// so `Pivot(1970)` reads 69 as 2069 and 70 as 1970.
+    Pivot(i32),
+    /// Two digit years are the closest year to the given date, from 50
+    /// years before to 49 years after it.
+    Reference(NaiveDate),
+}
+
+impl YearPolicy {
+    pub(crate) fn expand(self, year: i32, digits: usize) -> i32 {
+        if digits == 3 {
+            return year + 1900;
+        }
+        let start = match self {
+            YearPolicy::Rfc2822 => 1950,
+            YearPolicy::Pivot(pivot) => pivot,
+            YearPolicy::Reference(date) => date.year() - 50,
+        };
+        start + (year - start).rem_euclid(100)
+    }
+}
+
+/// What to do with fractional seconds, as in "16:00:00.123".
+///
+/// chrono does not accept them, so they are always removed from the
+/// sanitized string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FractionPolicy {
+    /// Drop them. This is the default.
+    #[default]
+    Truncate,
+    /// Put them back into the parsed date, as nanoseconds.
+    Preserve,
+}
+
+/// The format a date was recovered from, tried in this order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// Valid RFC 2822, as is.
+    Rfc2822,
+    /// RFC 2822 once sanitized.
+    SanitizedRfc2822,
+    /// RFC 3339, like "2017-07-31T16:00:00Z".
+    Rfc3339,
+    /// ISO 8601 that is not RFC 3339, like "2017-07-31 16:00:00".
+    /// Dates without an offset are at the default offset of the sanitizer,
+    /// and fail to parse if there is none, like RFC 2822 dates.
+    Iso8601,
+    /// Seconds since the Unix epoch, see `Sanitizer::epoch_fallback`.
+    EpochSeconds,
+    /// Milliseconds since the Unix epoch, see `Sanitizer::epoch_fallback`.
+    EpochMillis,
+}
+
+/// A date recovered by `Sanitizer::parse_with_report`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Parsed {
+    /// The recovered date.
+    pub datetime: DateTime<FixedOffset>,
+    /// The format that matched.
+    pub format: Format,
+    /// What the sanitizer did, `None` if chrono parsed the input on its own.
+    pub report: Option<SanitizeReport>,
+    /// The input named a weekday the date does not fall on.
+    pub weekday_mismatch: bool,
+}
+
+/// The knobs of a `Sanitizer`, shared by its fixups.
+#[derive(Debug, Clone)]
+pub(crate) struct Config {
+    pub(crate) locales: Vec<Locale>,
+    pub(crate) weekday_policy: WeekdayPolicy,
+    pub(crate) year_policy: YearPolicy,
+    pub(crate) fraction_policy: FractionPolicy,
+    pub(crate) zones: ZoneTable,
+    pub(crate) resolver: ZoneAbbrevResolver,
+    pub(crate) default_offset: Option<FixedOffset>,
+    pub(crate) check_zone_comment: bool,
+    pub(crate) epoch_fallback: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            locales: Locale::ALL.to_vec(),
+            weekday_policy: WeekdayPolicy::default(),
+            year_policy: YearPolicy::default(),
+            fraction_policy: FractionPolicy::default(),
+            zones: ZoneTable::new(),
+            resolver: ZoneAbbrevResolver::new(),
+            default_offset: None,
+            check_zone_comment: false,
+            epoch_fallback: false,
+        }
+    }
+}
+
+lazy_static! {
+    static ref DEFAULT_CONFIG: Config = Config::default();
+}
+
+/// State threaded through the fixups of a single sanitization.
+#[derive(Debug)]
+pub struct Context<'a> {
+    config: &'a Config,
+    fixes: Vec<Fix>,
+    /// Span of each fix in the string it was applied to, and the length of
+    /// the text it replaced, to map spans back to the input.
+    edits: Vec<(Range<usize>, usize)>,
+    weekday: Option<Weekday>,
+    ambiguous_zone: Option<String>,
+    zone_mismatch: Option<String>,
+    nanosecond: Option<u32>,
+}
+
+impl Context<'static> {
+    /// Create an empty `Context` with the default configuration.
+    pub fn new() -> Self {
+        Context::with_config(&DEFAULT_CONFIG)
+    }
+}
+
+impl Default for Context<'static> {
+    fn default() -> Self {
+        Context::new()
+    }
+}
+
+impl<'a> Context<'a> {
+    pub(crate) fn with_config(config: &'a Config) -> Self {
+        Context {
+            config,
+            fixes: Vec::new(),
+            edits: Vec::new(),
+            weekday: None,
+            ambiguous_zone: None,
+            zone_mismatch: None,
+            nanosecond: None,
+        }
+    }
+
+    /// The repairs recorded so far.
+    pub fn fixes(&self) -> &[Fix] {
+        &self.fixes
+    }
+
+    /// The timezone abbreviations known to this sanitization.
+    pub fn zones(&self) -> &ZoneTable {
+        &self.config.zones
+    }
+
+    /// Picks the meaning of ambiguous timezone abbreviations.
+    pub fn resolver(&self) -> &ZoneAbbrevResolver {
+        &self.config.resolver
+    }
+
+    /// How two and three digit years are expanded.
+    pub fn year_policy(&self) -> YearPolicy {
+        self.config.year_policy
+    }
+
+    /// The offset to assume for dates without a timezone, if any.
+    pub fn default_offset(&self) -> Option<FixedOffset> {
+        self.config.default_offset
+    }
+
+    /// Record that the ambiguous timezone `abbr` was given its default meaning.
+    pub fn set_ambiguous_zone(&mut self, abbr: &str) {
+        self.ambiguous_zone = Some(abbr.to_string());
+    }
+
+    /// Record that the timezone `name`, given in a comment, does not match
+    /// the numeric offset of the date.
+    pub fn set_zone_mismatch(&mut self, name: &str) {
+        self.zone_mismatch = Some(name.to_string());
+    }
+
+    /// The fractional seconds a fixup removed from the input, in nanoseconds.
+    pub fn nanosecond(&self) -> Option<u32> {
+        self.nanosecond
+    }
+
+    /// Record the fractional seconds of the input before removing them.
+    pub fn set_nanosecond(&mut self, nanosecond: u32) {
+        self.nanosecond = Some(nanosecond);
+    }
+
+    /// The weekday a fixup removed from the input, if any.
+    pub fn weekday(&self) -> Option<Weekday> {
+        self.weekday
+    }
+
+    /// Record the weekday named by the input before removing it,
+    /// so it can be checked against the date once parsed.
+    pub fn set_weekday(&mut self, weekday: Weekday) {
+        self.weekday = Some(weekday);
+    }
+
+    /// The locales month and weekday names are looked up in.
+    pub fn locales(&self) -> &[Locale] {
+        &self.config.locales
+    }
+
+    /// Replace `span` of `s` with `replacement` and record it as a fix of `kind`.
+    pub fn replace(
+        &mut self,
+        kind: FixKind,
+        s: &mut String,
+        span: Range<usize>,
+        replacement: &str,
+    ) {
+        let original = s[span.clone()].to_string();
+        let input_span = self.input_span(span.clone());
+        s.replace_range(span.clone(), replacement);
+        self.edits
+            .push((span.start..span.start + replacement.len(), original.len()));
+        self.fixes.push(Fix {
+            kind,
+            span: input_span,
+            original,
+            replacement: replacement.to_string(),
+        });
+    }
+
+    /// Map `span` of the string as it is now back to the input, undoing the
+    /// fixes recorded so far. Spans that reach into a replacement cover all
+    /// of the text it replaced.
+    fn input_span(&self, span: Range<usize>) -> Range<usize> {
+        let (mut start, mut end) = (span.start, span.end);
+        for &(ref edit, old_len) in self.edits.iter().rev() {
+            let shift = |pos: usize| pos - edit.len() + old_len;
+            start = if start >= edit.end {
+                shift(start)
+            } else {
+                start.min(edit.start)
+            };
+            end = if end <= edit.start {
+                end
+            } else if end < edit.end {
+                edit.start + old_len
+            } else {
+                shift(end)
+            };
+        }
+        start..end.max(start)
+    }
+
+    /// Apply several replacements at once.
+    ///
+    /// The spans must be sorted and not overlap, all of them relative to `s`
+    /// as it was passed in.
+    pub fn replace_all<I>(&mut self, kind: FixKind, s: &mut String, edits: I)
+    where
+        I: IntoIterator<Item = (Range<usize>, String)>,
+    {
+        // Walk the edits back to front so earlier spans stay valid.
+        let edits: Vec<_> = edits.into_iter().collect();
+        let first = self.fixes.len();
+        for (span, replacement) in edits.into_iter().rev() {
+            self.replace(kind, s, span, &replacement);
+        }
+        self.fixes[first..].reverse();
+    }
+}
+
+/// A configurable pipeline of fixups.
+///
+/// `Sanitizer::new()` runs the same stages, in the same order, as
+/// `sanitize_rfc822_like_date`. Stages can be dropped with `without`,
+/// and added or moved around with `with`, `before` and `after`.
+///
+/// ```rust
+/// # extern crate rfc822_sanitizer;
+/// use rfc822_sanitizer::fixups::ReplaceMonth;
+/// use rfc822_sanitizer::{FixKind, Sanitizer};
+///
+/// # fn main() {
+/// // Fix the months before anything else, and leave the weekday alone.
+/// let sanitizer = Sanitizer::new()
+///     .without(FixKind::ReplaceMonth)
+///     .without(FixKind::RemoveWeekday)
+///     .before(FixKind::NormalizeWhitespace, ReplaceMonth);
+///
+/// assert_eq!(
+///     &sanitizer.kinds()[..2],
+///     &[FixKind::ReplaceMonth, FixKind::NormalizeWhitespace]
+/// );
+/// assert_eq!(
+///     sanitizer.sanitize("Mon, 31 July 2017 1:00:00 -0000").sanitized,
+///     "Mon, 31 Jul 2017 01:00:00 +0000"
+/// );
+/// # }
+/// ```
+pub struct Sanitizer {
+    fixups: Vec<Box<dyn Fixup>>,
+    config: Config,
+}
+
+impl Default for Sanitizer {
+    fn default() -> Self {
+        Sanitizer::new()
+    }
+}
+
+impl fmt::Debug for Sanitizer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Sanitizer")
+            .field("fixups", &self.kinds())
+            .field("locales", &self.config.locales)
+            .field("weekday_policy", &self.config.weekday_policy)
+            .field("year_policy", &self.config.year_policy)
+            .field("fraction_policy", &self.config.fraction_policy)
+            .field("zones", &self.config.zones)
+            .field("resolver", &self.config.resolver)
+            .field("default_offset", &self.config.default_offset)
+            .field("check_zone_comment", &self.config.check_zone_comment)
+            .field("epoch_fallback", &self.config.epoch_fallback)
+            .finish()
+    }
+}
+
+impl Sanitizer {
+    /// The default pipeline, as used by `sanitize_rfc822_like_date`.
+    pub fn new() -> Self {
+        Sanitizer::empty()
+            .with(NormalizeWhitespace)
+            .with(StripComments)
+            .with(StripFraction)
+            .with(ConvertMeridiem)
+            .with(PadZeros)
+            .with(AddSeconds)
+            .with(RemoveWeekday)
+            .with(StripOrdinal)
+            .with(ReplaceMonth)
+            .with(ReorderDate)
+            .with(ExpandYear)
+            .with(NormalizeOffset)
+            .with(ReplaceLeadingZeros)
+            .with(ReplaceZone)
+            .with(AssumeOffset)
+    }
+
+    /// A pipeline with no fixups at all.
+    pub fn empty() -> Self {
+        Sanitizer {
+            fixups: Vec::new(),
+            config: Config::default(),
+        }
+    }
+
+    /// Only look up month and weekday names in the given locales.
+    ///
+    /// By default every `Locale` is tried. English is always tried,
+    /// as the RFC 2822 names are English.
+    ///
+    /// ```rust
+    /// # extern crate rfc822_sanitizer;
+    /// use rfc822_sanitizer::locale::Locale;
+    /// use rfc822_sanitizer::Sanitizer;
+    ///
+    /// # fn main() {
+    /// let french = Sanitizer::new().locales(&[Locale::French]);
+    /// assert_eq!(
+    ///     french.sanitize("mar., 12 sept. 2017 10:00:00 +0200").sanitized,
+    ///     "12 Sep 2017 10:00:00 +0200"
+    /// );
+    ///
+    /// let english = Sanitizer::new().locales(&[Locale::English]);
+    /// assert_eq!(
+    ///     english.sanitize("12 févr. 2017 10:00:00 +0200").sanitized,
+    ///     "12 févr. 2017 10:00:00 +0200"
+    /// );
+    /// # }
+    /// ```
+    pub fn locales(mut self, locales: &[Locale]) -> Self {
+        self.config.locales = locales.to_vec();
+        if !locales.contains(&Locale::English) {
+            self.config.locales.insert(0, Locale::English);
+        }
+        self
+    }
+
+    /// Choose what to do when the weekday does not match the date.
+    pub fn weekday_policy(mut self, policy: WeekdayPolicy) -> Self {
+        self.config.weekday_policy = policy;
+        self
+    }
+
+    /// Choose how two and three digit years are expanded.
+    pub fn year_policy(mut self, policy: YearPolicy) -> Self {
+        self.config.year_policy = policy;
+        self
+    }
+
+    /// Choose whether fractional seconds end up in the parsed date.
+    ///
+    /// ```rust
+    /// # extern crate chrono; extern crate rfc822_sanitizer;
+    /// use chrono::Timelike;
+    /// use rfc822_sanitizer::{FractionPolicy, Sanitizer};
+    ///
+    /// # fn main() {
+    /// let input = "Mon, 31 Jul 2017 16:00:00.123 +0000";
+    /// let truncated = Sanitizer::new().parse(input).unwrap();
+    /// assert_eq!(truncated.nanosecond(), 0);
+    ///
+    /// let preserved = Sanitizer::new()
+    ///     .fraction_policy(FractionPolicy::Preserve)
+    ///     .parse(input)
+    ///     .unwrap();
+    /// assert_eq!(preserved.nanosecond(), 123_000_000);
+    /// # }
+    /// ```
+    pub fn fraction_policy(mut self, policy: FractionPolicy) -> Self {
+        self.config.fraction_policy = policy;
+        self
+    }
+
+    /// Use `zones` to rewrite timezone abbreviations, instead of the built-in table.
+    ///
+    /// Its offsets win over the ones chrono gives `PDT`, `EST` and the other
+    /// US zones on its own.
+    pub fn zones(mut self, zones: ZoneTable) -> Self {
+        self.config.zones = zones;
+        self
+    }
+
+    /// Use `resolver` to pick the meaning of ambiguous timezone abbreviations.
+    ///
+    /// As chrono reads `EST`, `CST` and `MST` as US zones, dates using them
+    /// are always sanitized with a resolver other than the default one.
+    ///
+    /// ```rust
+    /// # extern crate chrono; extern crate rfc822_sanitizer;
+    /// # use chrono::DateTime;
+    /// use rfc822_sanitizer::zone::{Region, ZoneAbbrevResolver};
+    /// use rfc822_sanitizer::Sanitizer;
+    ///
+    /// # fn main() {
+    /// let sanitizer =
+    ///     Sanitizer::new().zone_resolver(ZoneAbbrevResolver::new().prefer(Region::EastAsia));
+    /// assert_eq!(
+    ///     sanitizer.parse("Mon, 31 Jul 2017 16:00:00 CST").unwrap(),
+    ///     DateTime::parse_from_rfc2822("Mon, 31 Jul 2017 16:00:00 +0800").unwrap()
+    /// );
+    /// # }
+    /// ```
+    pub fn zone_resolver(mut self, resolver: ZoneAbbrevResolver) -> Self {
+        self.config.resolver = resolver;
+        self
+    }
+
+    /// Assume `offset` for dates that have no timezone at all.
+    ///
+    /// The offset is appended by the `AssumeOffset` stage, so the report of
+    /// such dates says `FixKind::AssumeOffset` was applied. ISO 8601 dates
+    /// without an offset are taken to be at `offset` as well.
+    ///
+    /// ```rust
+    /// # extern crate chrono; extern crate rfc822_sanitizer;
+    /// # use chrono::{DateTime, FixedOffset};
+    /// use rfc822_sanitizer::{FixKind, Sanitizer};
+    ///
+    /// # fn main() {
+    /// let sanitizer = Sanitizer::new().default_offset(FixedOffset::east(2 * 3600));
+    /// let parsed = sanitizer
+    ///     .parse_with_report("Mon, 31 Jul 2017 16:00:00")
+    ///     .unwrap();
+    ///
+    /// assert!(parsed.report.unwrap().applied(FixKind::AssumeOffset));
+    /// assert_eq!(
+    ///     parsed.datetime,
+    ///     DateTime::parse_from_rfc2822("Mon, 31 Jul 2017 16:00:00 +0200").unwrap()
+    /// );
+    /// # }
+    /// ```
+    pub fn default_offset(mut self, offset: FixedOffset) -> Self {
+        self.config.default_offset = Some(offset);
+        self
+    }
+
+    /// Assume UTC for dates that have no timezone at all.
+    ///
+    /// Same as `default_offset(FixedOffset::east(0))`.
+    pub fn assume_utc(self) -> Self {
+        self.default_offset(FixedOffset::east(0))
+    }
+
+    /// Fail with `Error::ZoneMismatch` when a timezone named in a comment,
+    /// as in "-0800 (PDT)", does not match the offset before it.
+    ///
+    /// Off by default, the comment is then dropped and the offset trusted.
+    /// Either way `SanitizeReport::zone_mismatch` records the mismatch.
+    ///
+    /// ```rust
+    /// # extern crate rfc822_sanitizer;
+    /// use rfc822_sanitizer::{Error, Sanitizer};
+    ///
+    /// # fn main() {
+    /// let sanitizer = Sanitizer::new().check_zone_comment(true);
+    /// assert!(sanitizer.parse("Mon, 31 Jul 2017 16:00:00 -0700 (PDT)").is_ok());
+    ///
+    /// match sanitizer.parse("Mon, 31 Jul 2017 16:00:00 -0800 (PDT)") {
+    ///     Err(Error::ZoneMismatch { zone, .. }) => assert_eq!(zone, "PDT"),
+    ///     other => panic!("unexpected result: {:?}", other),
+    /// }
+    /// # }
+    /// ```
+    pub fn check_zone_comment(mut self, check: bool) -> Self {
+        self.config.check_zone_comment = check;
+        self
+    }
+
+    /// Read dates made only of digits as seconds or milliseconds since the
+    /// Unix epoch, once every other format failed.
+    ///
+    /// Off by default. To avoid mistaking other numbers for dates, only 9 or
+    /// 10 digit seconds and 12 or 13 digit milliseconds between 1980 and 2100
+    /// are accepted. They are returned in UTC.
+    ///
+    /// ```rust
+    /// # extern crate chrono; extern crate rfc822_sanitizer;
+    /// # use chrono::DateTime;
+    /// use rfc822_sanitizer::{Format, Sanitizer};
+    ///
+    /// # fn main() {
+    /// let sanitizer = Sanitizer::new().epoch_fallback(true);
+    /// let parsed = sanitizer.parse_with_report("1501516800").unwrap();
+    ///
+    /// assert_eq!(parsed.format, Format::EpochSeconds);
+    /// assert_eq!(
+    ///     parsed.datetime,
+    ///     DateTime::parse_from_rfc2822("Mon, 31 Jul 2017 16:00:00 +0000").unwrap()
+    /// );
+    /// assert!(sanitizer.parse("20170731").is_err());
+    /// # }
+    /// ```
+    pub fn epoch_fallback(mut self, enabled: bool) -> Self {
+        self.config.epoch_fallback = enabled;
+        self
+    }
+
+    /// Append `fixup` to the end of the pipeline.
+    pub fn with<F: Fixup + 'static>(mut self, fixup: F) -> Self {
+        self.fixups.push(Box::new(fixup));
+        self
+    }
+
+    /// Remove every stage of the given kind.
+    pub fn without(mut self, kind: FixKind) -> Self {
+        self.fixups.retain(|f| f.kind() != kind);
+        self
+    }
+
+    /// Insert `fixup` right before the first stage of kind `anchor`.
+    ///
+    /// Appends it if there is no such stage.
+    pub fn before<F: Fixup + 'static>(mut self, anchor: FixKind, fixup: F) -> Self {
+        let idx = self.position(anchor).unwrap_or(self.fixups.len());
+        self.fixups.insert(idx, Box::new(fixup));
+        self
+    }
+
+    /// Insert `fixup` right after the first stage of kind `anchor`.
+    ///
+    /// Appends it if there is no such stage.
+    pub fn after<F: Fixup + 'static>(mut self, anchor: FixKind, fixup: F) -> Self {
+        let idx = self
+            .position(anchor)
+            .map(|i| i + 1)
+            .unwrap_or(self.fixups.len());
+        self.fixups.insert(idx, Box::new(fixup));
+        self
+    }
+
+    /// The kinds of the stages, in the order they run.
+    pub fn kinds(&self) -> Vec<FixKind> {
+        self.fixups.iter().map(|f| f.kind()).collect()
+    }
+
+    fn position(&self, kind: FixKind) -> Option<usize> {
+        self.fixups.iter().position(|f| f.kind() == kind)
+    }
+
+    /// Run every stage over `s`, reporting which repairs were applied.
+    pub fn sanitize<S: Into<String>>(&self, s: S) -> SanitizeReport {
+        let mut cx = Context::with_config(&self.config);
+        let s = self
+            .fixups
+            .iter()
+            .fold(s.into(), |s, fixup| fixup.apply(s, &mut cx));
+
+        SanitizeReport {
+            sanitized: s,
+            fixes: cx.fixes,
+            weekday: cx.weekday,
+            ambiguous_zone: cx.ambiguous_zone,
+            zone_mismatch: cx.zone_mismatch,
+            nanosecond: cx.nanosecond,
+        }
+    }
+
+    /// Calls `DateTime::parse_from_rfc2822()`. If it succedes returns,
+    /// Else it runs the pipeline and retries, then falls back to RFC 3339
+    /// and ISO 8601.
+    ///
+    /// Same as `parse_from_rfc2822_with_fallback` for `Sanitizer::new()`.
+    pub fn parse<'s, S: Into<Cow<'s, str>>>(&self, s: S) -> Result<DateTime<FixedOffset>, Error> {
+        self.parse_with_report(s).map(|p| p.datetime)
+    }
+
+    /// Same as `parse`, but also reports how the date was recovered.
+    ///
+    /// ```rust
+    /// # extern crate chrono; extern crate rfc822_sanitizer;
+    /// # use chrono::DateTime;
+    /// use rfc822_sanitizer::{Format, Sanitizer, WeekdayPolicy};
+    ///
+    /// # fn main() {
+    /// // 05 Aug 2016 was a Friday.
+    /// let parsed = Sanitizer::new()
+    ///     .weekday_policy(WeekdayPolicy::TrustWeekday)
+    ///     .parse_with_report("Thu, 05 Aug 2016 06:00:00 -0400")
+    ///     .unwrap();
+    ///
+    /// assert!(parsed.weekday_mismatch);
+    /// assert_eq!(parsed.format, Format::SanitizedRfc2822);
+    /// assert_eq!(
+    ///     parsed.datetime,
+    ///     DateTime::parse_from_rfc2822("Thu, 04 Aug 2016 06:00:00 -0400").unwrap()
+    /// );
+    /// # }
+    /// ```
+    pub fn parse_with_report<'s, S: Into<Cow<'s, str>>>(&self, s: S) -> Result<Parsed, Error> {
+        let s = s.into();
+        // The zone table or resolver may give the US zones chrono knows
+        // another meaning, and a custom year policy two digit years another
+        // century, so only trust chrono on its own when they agree. Ambiguous
+        // zones like CST still go through the pipeline, to be reported.
+        let trust_chrono = self.config.year_policy == YearPolicy::Rfc2822 && !self.rereads_zone(&s);
+        let original = match DateTime::parse_from_rfc2822(&s) {
+            Ok(datetime) if trust_chrono => {
+                return Ok(Parsed {
+                    datetime,
+                    format: Format::Rfc2822,
+                    report: None,
+                    weekday_mismatch: false,
+                })
+            }
+            original => original,
+        };
+
+        let as_is = original.ok();
+        let report = self.sanitize(&*s);
+        let datetime = match (DateTime::parse_from_rfc2822(&report.sanitized), original) {
+            (Ok(dt), _) => dt,
+            (Err(_), Ok(datetime)) => {
+                return Ok(Parsed {
+                    datetime,
+                    format: Format::Rfc2822,
+                    report: None,
+                    weekday_mismatch: false,
+                })
+            }
+            (Err(sanitized), Err(original)) => {
+                if let Some(parsed) = self.parse_fallback(&s) {
+                    return Ok(parsed);
+                }
+                return Err(Error::Unparseable {
+                    original,
+                    sanitized,
+                    stage: report.fixes.last().map(|f| f.kind),
+                    sanitized_input: report.sanitized,
+                });
+            }
+        };
+
+        let datetime = match (self.config.fraction_policy, report.nanosecond) {
+            (FractionPolicy::Preserve, Some(nanosecond)) => {
+                datetime.with_nanosecond(nanosecond).unwrap_or(datetime)
+            }
+            _ => datetime,
+        };
+
+        if self.config.check_zone_comment {
+            if let Some(ref zone) = report.zone_mismatch {
+                return Err(Error::ZoneMismatch {
+                    zone: zone.clone(),
+                    datetime,
+                });
+            }
+        }
+
+        // Ambiguous zones get here, even though chrono took them as is.
+        let format = if as_is == Some(datetime) {
+            Format::Rfc2822
+        } else {
+            Format::SanitizedRfc2822
+        };
+        let weekday = match report.weekday {
+            Some(weekday) if weekday != datetime.weekday() => weekday,
+            _ => {
+                return Ok(Parsed {
+                    datetime,
+                    format,
+                    report: Some(report),
+                    weekday_mismatch: false,
+                })
+            }
+        };
+
+        let datetime = match self.config.weekday_policy {
+            WeekdayPolicy::TrustDate => datetime,
+            WeekdayPolicy::TrustWeekday => shift_to_weekday(datetime, weekday),
+            WeekdayPolicy::Reject => return Err(Error::WeekdayMismatch { weekday, datetime }),
+        };
+
+        Ok(Parsed {
+            datetime,
+            format,
+            report: Some(report),
+            weekday_mismatch: true,
+        })
+    }
+
+    /// Returns `true` if the last word of `s` is a timezone abbreviation
+    /// that would be given its default meaning, or another offset than the
+    /// one chrono gives it.
+    fn rereads_zone(&self, s: &str) -> bool {
+        let abbr = match s.split_whitespace().last() {
+            Some(abbr) => abbr,
+            None => return false,
+        };
+        match self.config.resolver.resolve(abbr, &self.config.zones) {
+            Some(resolved) => {
+                resolved.by_default || zone::rfc2822_offset(abbr) != Some(resolved.offset)
+            }
+            None => false,
+        }
+    }
+
+    /// Try the formats that come after RFC 2822, see `Format`.
+    fn parse_fallback(&self, s: &str) -> Option<Parsed> {
+        let offset = self.config.default_offset;
+        fallback::parse_rfc3339(s)
+            .map(|dt| (dt, Format::Rfc3339))
+            .or_else(|| fallback::parse_iso8601(s, offset).map(|dt| (dt, Format::Iso8601)))
+            .or_else(|| {
+                if self.config.epoch_fallback {
+                    fallback::parse_epoch(s)
+                } else {
+                    None
+                }
+            })
+            .map(|(datetime, format)| Parsed {
+                datetime,
+                format,
+                report: None,
+                weekday_mismatch: false,
+            })
+    }
+}
+
+/// Move `dt` to the closest day that falls on `weekday`, at most 3 days away.
+fn shift_to_weekday(dt: DateTime<FixedOffset>, weekday: Weekday) -> DateTime<FixedOffset> {
+    let ahead = (7 + weekday.num_days_from_monday() - dt.weekday().num_days_from_monday()) % 7;
+    let days = if ahead > 3 {
+        i64::from(ahead) - 7
+    } else {
+        i64::from(ahead)
+    };
+    dt + Duration::days(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Shout;
+
+    impl Fixup for Shout {
+        fn kind(&self) -> FixKind {
+            FixKind::Custom("shout")
+        }
+
+        fn apply(&self, s: String, cx: &mut Context) -> String {
+            let mut s = s;
+            let edits: Vec<_> = s
+                .match_indices("Jul")
+                .map(|(i, m)| (i..i + m.len(), m.to_uppercase()))
+                .collect();
+            cx.replace_all(self.kind(), &mut s, edits);
+            s
+        }
+    }
+
+    #[test]
+    fn test_pipeline_order() {
+        let sanitizer = Sanitizer::new();
+        assert_eq!(
+            sanitizer.kinds(),
+            vec![
+                FixKind::NormalizeWhitespace,
+                FixKind::StripComments,
+                FixKind::StripFraction,
+                FixKind::ConvertMeridiem,
+                FixKind::PadZeros,
+                FixKind::AddSeconds,
+                FixKind::RemoveWeekday,
+                FixKind::StripOrdinal,
+                FixKind::ReplaceMonth,
+                FixKind::ReorderDate,
+                FixKind::ExpandYear,
+                FixKind::NormalizeOffset,
+                FixKind::ReplaceLeadingZeros,
+                FixKind::ReplaceZone,
+                FixKind::AssumeOffset,
+            ]
+        );
+
+        let sanitizer = Sanitizer::new()
+            .without(FixKind::RemoveWeekday)
+            .after(FixKind::ReplaceMonth, Shout)
+            .before(FixKind::PadZeros, RemoveWeekday);
+        assert_eq!(
+            sanitizer.kinds(),
+            vec![
+                FixKind::NormalizeWhitespace,
+                FixKind::StripComments,
+                FixKind::StripFraction,
+                FixKind::ConvertMeridiem,
+                FixKind::RemoveWeekday,
+                FixKind::PadZeros,
+                FixKind::AddSeconds,
+                FixKind::StripOrdinal,
+                FixKind::ReplaceMonth,
+                FixKind::Custom("shout"),
+                FixKind::ReorderDate,
+                FixKind::ExpandYear,
+                FixKind::NormalizeOffset,
+                FixKind::ReplaceLeadingZeros,
+                FixKind::ReplaceZone,
+                FixKind::AssumeOffset,
+            ]
+        );
+
+        let sanitizer = Sanitizer::empty().after(FixKind::PadZeros, Shout);
+        assert_eq!(sanitizer.kinds(), vec![FixKind::Custom("shout")]);
+    }
+
+    #[test]
+    fn test_custom_fixup() {
+        let sanitizer = Sanitizer::new().with(Shout);
+        let report = sanitizer.sanitize("Mon, 31 July 2017 Jul 16:00:00 -0000");
+        assert_eq!(report.sanitized, "31 JUL 2017 JUL 16:00:00 +0000");
+
+        let shouted: Vec<_> = report
+            .fixes
+            .iter()
+            .filter(|f| f.kind == FixKind::Custom("shout"))
+            .map(|f| f.span.clone())
+            .collect();
+        // "JUL" took the place of "July", and "Jul" kept its own.
+        assert_eq!(shouted, vec![8..12, 18..21]);
+    }
+
+    #[test]
+    fn test_weekday_policy() {
+        // 05 Aug 2016 was a Friday.
+        let input = "Thu, 05 Aug 2016 06:00:00 -0400";
+        let friday = DateTime::parse_from_rfc2822("Fri, 05 Aug 2016 06:00:00 -0400").unwrap();
+        let thursday = DateTime::parse_from_rfc2822("Thu, 04 Aug 2016 06:00:00 -0400").unwrap();
+
+        let parsed = Sanitizer::new().parse_with_report(input).unwrap();
+        assert_eq!(parsed.datetime, friday);
+        assert!(parsed.weekday_mismatch);
+        assert_eq!(parsed.report.unwrap().weekday, Some(Weekday::Thu));
+
+        let parsed = Sanitizer::new()
+            .weekday_policy(WeekdayPolicy::TrustWeekday)
+            .parse_with_report(input)
+            .unwrap();
+        assert_eq!(parsed.datetime, thursday);
+        assert!(parsed.weekday_mismatch);
+
+        let err = Sanitizer::new()
+            .weekday_policy(WeekdayPolicy::Reject)
+            .parse(input)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            Error::WeekdayMismatch {
+                weekday: Weekday::Thu,
+                datetime: friday,
+            }
+        );
+
+        // Shifts go both ways, never more than 3 days.
+        let foo = [
+            ("Mon, 05 Aug 2016 06:00:00 -0400", 8),
+            ("Tue, 05 Aug 2016 06:00:00 -0400", 2),
+            ("Wed, 05 Aug 2016 06:00:00 -0400", 3),
+            ("Sat, 05 Aug 2016 06:00:00 -0400", 6),
+            ("Sun, 05 Aug 2016 06:00:00 -0400", 7),
+        ];
+        let sanitizer = Sanitizer::new().weekday_policy(WeekdayPolicy::TrustWeekday);
+        foo.iter().for_each(|&(input, day)| {
+            assert_eq!(sanitizer.parse(input).unwrap().day(), day);
+        });
+
+        // A matching weekday is not a mismatch, even if something else was fixed.
+        let parsed = Sanitizer::new()
+            .weekday_policy(WeekdayPolicy::Reject)
+            .parse_with_report("Fri, 05 August 2016 06:00:00 -0400")
+            .unwrap();
+        assert_eq!(parsed.datetime, friday);
+        assert!(!parsed.weekday_mismatch);
+
+        let parsed = Sanitizer::new()
+            .parse_with_report("Fri, 05 Aug 2016 06:00:00 -0400")
+            .unwrap();
+        assert_eq!(parsed.report, None);
+    }
+
+    #[test]
+    fn test_parse() {
+        let sanitizer = Sanitizer::new();
+        assert_eq!(
+            sanitizer.parse("Thu, 30 Aug 2017 1:30:00 PDT").unwrap(),
+            DateTime::parse_from_rfc2822("30 Aug 2017 01:30:00 PDT").unwrap()
+        );
+
+        // Without the padding the hour stays invalid.
+        let err = sanitizer
+            .without(FixKind::PadZeros)
+            .parse("Thu, 30 Aug 2017 1:30:00 PDT")
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid date (input contains invalid characters), \
+             still invalid after weekday removal: \"30 Aug 2017 1:30:00 PDT\" \
+             (input contains invalid characters)"
+        );
+
+        // chrono reads 69 as 1969 on its own.
+        let sanitizer = Sanitizer::new().year_policy(YearPolicy::Pivot(1970));
+        assert_eq!(
+            sanitizer.parse("Mon, 31 Jul 69 16:00:00 GMT").unwrap(),
+            DateTime::parse_from_rfc2822("31 Jul 2069 16:00:00 GMT").unwrap()
+        );
+
+        // Nor does it know about the zone table.
+        let mut zones = ZoneTable::new();
+        zones.insert("PDT", FixedOffset::east(0));
+        assert_eq!(
+            Sanitizer::new()
+                .zones(zones)
+                .parse("Mon, 31 Jul 2017 16:00:00 PDT")
+                .unwrap()
+                .offset(),
+            &FixedOffset::east(0)
+        );
+    }
+
+    #[test]
+    fn test_parse_format() {
+        let dt = DateTime::parse_from_rfc3339("2017-07-31T16:00:00+00:00").unwrap();
+        let foo = [
+            ("Mon, 31 Jul 2017 16:00:00 +0000", Format::Rfc2822),
+            ("Mon, 31 July 2017 16:00:00 +0000", Format::SanitizedRfc2822),
+            ("2017-07-31T16:00:00Z", Format::Rfc3339),
+            ("2017-07-31T18:00:00+02:00", Format::Rfc3339),
+            ("2017-07-31 16:00:00Z", Format::Iso8601),
+            ("2017-07-31T16:00Z", Format::Iso8601),
+        ];
+
+        let sanitizer = Sanitizer::new();
+        foo.iter().for_each(|&(input, format)| {
+            let parsed = sanitizer.parse_with_report(input).unwrap();
+            assert_eq!(parsed.datetime, dt);
+            assert_eq!(parsed.format, format);
+        });
+
+        // chrono knows CST, but it is still reported as ambiguous.
+        let foo = [
+            ("Mon, 31 Jul 2017 10:00:00 CST", "CST", Format::Rfc2822),
+            ("Mon, 31 Jul 2017 11:00:00 EST", "EST", Format::Rfc2822),
+            (
+                "Mon, 31 Jul 2017 17:00:00 BST",
+                "BST",
+                Format::SanitizedRfc2822,
+            ),
+        ];
+        foo.iter().for_each(|&(input, zone, format)| {
+            let parsed = sanitizer.parse_with_report(input).unwrap();
+            assert_eq!(parsed.datetime, dt, "{}", input);
+            assert_eq!(parsed.format, format, "{}", input);
+            let ambiguous_zone = parsed.report.and_then(|r| r.ambiguous_zone);
+            assert_eq!(ambiguous_zone, Some(zone.to_string()), "{}", input);
+        });
+
+        // Dates without an offset need a default one.
+        assert!(sanitizer.parse("2017-07-31 16:00:00").is_err());
+        let parsed = Sanitizer::new()
+            .default_offset(FixedOffset::east(2 * 3600))
+            .parse_with_report("2017-07-31 18:00:00")
+            .unwrap();
+        assert_eq!(parsed.datetime, dt);
+        assert_eq!(parsed.format, Format::Iso8601);
+
+        assert!(sanitizer.parse("2017-07-32T16:00:00Z").is_err());
+
+        // Epoch timestamps are opt-in.
+        assert!(sanitizer.parse("1501516800").is_err());
+        let sanitizer = Sanitizer::new().epoch_fallback(true);
+        let parsed = sanitizer.parse_with_report("1501516800000").unwrap();
+        assert_eq!(parsed.datetime, dt);
+        assert_eq!(parsed.format, Format::EpochMillis);
+    }
+}
@@ -0,0 +1,449 @@
+//! Timezone abbreviations.
+//!
+//! chrono only understands the zone names RFC 2822 lists: `UT`, `GMT` and the
+//! US zones (`EST`, `PDT`, …). Any other name is treated as an unknown zone,
+//! so the date fails to parse. A `ZoneTable` maps the other common
+//! abbreviations to numeric offsets, so they can be rewritten as `+HHMM`.
+
+use chrono::FixedOffset;
+use std::collections::HashMap;
+
+const H: i32 = 3600;
+const M: i32 = 60;
+
+/// Built-in abbreviations and their offset from UTC, in seconds.
+static ZONES: &[(&str, i32)] = &[
+    // Universal
+    ("UT", 0),
+    ("UTC", 0),
+    ("GMT", 0),
+    ("Z", 0),
+    // Europe
+    ("WET", 0),
+    ("WEST", H),
+    ("BST", H),
+    ("IST", 5 * H + 30 * M),
+    ("CET", H),
+    ("CEST", 2 * H),
+    ("MET", H),
+    ("MEST", 2 * H),
+    ("MEZ", H),
+    ("MESZ", 2 * H),
+    ("EET", 2 * H),
+    ("EEST", 3 * H),
+    ("MSK", 3 * H),
+    ("TRT", 3 * H),
+    // Africa
+    ("WAT", H),
+    ("CAT", 2 * H),
+    ("SAST", 2 * H),
+    ("EAT", 3 * H),
+    // Asia
+    ("IRST", 3 * H + 30 * M),
+    ("GST", 4 * H),
+    ("AFT", 4 * H + 30 * M),
+    ("PKT", 5 * H),
+    ("NPT", 5 * H + 45 * M),
+    ("ICT", 7 * H),
+    ("WIB", 7 * H),
+    ("HKT", 8 * H),
+    ("SGT", 8 * H),
+    ("MYT", 8 * H),
+    ("PHT", 8 * H),
+    ("WITA", 8 * H),
+    ("JST", 9 * H),
+    ("KST", 9 * H),
+    ("WIT", 9 * H),
+    // Oceania
+    ("AWST", 8 * H),
+    ("ACST", 9 * H + 30 * M),
+    ("ACDT", 10 * H + 30 * M),
+    ("AEST", 10 * H),
+    ("AEDT", 11 * H),
+    ("NZST", 12 * H),
+    ("NZDT", 13 * H),
+    // Americas
+    ("NST", -(3 * H + 30 * M)),
+    ("NDT", -(2 * H + 30 * M)),
+    ("AST", -4 * H),
+    ("ADT", -3 * H),
+    ("EST", -5 * H),
+    ("EDT", -4 * H),
+    ("CST", -6 * H),
+    ("CDT", -5 * H),
+    ("MST", -7 * H),
+    ("MDT", -6 * H),
+    ("PST", -8 * H),
+    ("PDT", -7 * H),
+    ("AKST", -9 * H),
+    ("AKDT", -8 * H),
+    ("HST", -10 * H),
+    ("HDT", -9 * H),
+    ("BRT", -3 * H),
+    ("BRST", -2 * H),
+    ("ART", -3 * H),
+    ("CLT", -4 * H),
+    ("CLST", -3 * H),
+    ("SST", -11 * H),
+];
+
+/// Where a timezone abbreviation is used, to tell ambiguous ones apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Region {
+    NorthAmerica,
+    LatinAmerica,
+    Europe,
+    Africa,
+    MiddleEast,
+    SouthAsia,
+    EastAsia,
+    Oceania,
+}
+
+/// Abbreviations with more than one meaning.
+///
+/// The meaning in `ZONES` is the default, the others are picked by region.
+static AMBIGUOUS: &[(&str, &[(Region, i32)])] = &[
+    (
+        "IST",
+        &[
+            (Region::SouthAsia, 5 * H + 30 * M),
+            (Region::Europe, H),
+            (Region::MiddleEast, 2 * H),
+        ],
+    ),
+    (
+        "CST",
+        &[
+            (Region::NorthAmerica, -6 * H),
+            (Region::EastAsia, 8 * H),
+            (Region::LatinAmerica, -5 * H),
+        ],
+    ),
+    (
+        "CDT",
+        &[
+            (Region::NorthAmerica, -5 * H),
+            (Region::LatinAmerica, -4 * H),
+        ],
+    ),
+    ("BST", &[(Region::Europe, H), (Region::SouthAsia, 6 * H)]),
+    (
+        "AST",
+        &[(Region::NorthAmerica, -4 * H), (Region::MiddleEast, 3 * H)],
+    ),
+    (
+        "GST",
+        &[(Region::MiddleEast, 4 * H), (Region::LatinAmerica, -2 * H)],
+    ),
+    (
+        "EST",
+        &[(Region::NorthAmerica, -5 * H), (Region::Oceania, 10 * H)],
+    ),
+    (
+        "MST",
+        &[(Region::NorthAmerica, -7 * H), (Region::EastAsia, 8 * H)],
+    ),
+    (
+        "SST",
+        &[(Region::Oceania, -11 * H), (Region::EastAsia, 8 * H)],
+    ),
+];
+
+/// Zone names chrono already understands, and their offsets.
+static RFC2822_ZONES: &[(&str, i32)] = &[
+    ("UT", 0),
+    ("GMT", 0),
+    ("EST", -5 * H),
+    ("EDT", -4 * H),
+    ("CST", -6 * H),
+    ("CDT", -5 * H),
+    ("MST", -7 * H),
+    ("MDT", -6 * H),
+    ("PST", -8 * H),
+    ("PDT", -7 * H),
+];
+
+/// A table of timezone abbreviations and their offsets from UTC.
+///
+/// `ZoneTable::new()` knows about 60 abbreviations used around the world.
+/// Lookups ignore case.
+///
+/// ```rust
+/// # extern crate chrono; extern crate rfc822_sanitizer;
+/// # use chrono::FixedOffset;
+/// use rfc822_sanitizer::zone::ZoneTable;
+///
+/// # fn main() {
+/// let mut zones = ZoneTable::new();
+/// assert_eq!(zones.offset("CEST"), Some(FixedOffset::east(2 * 3600)));
+/// assert_eq!(zones.offset("aest"), Some(FixedOffset::east(10 * 3600)));
+/// assert_eq!(zones.offset("XYZT"), None);
+///
+/// zones.insert("XYZT", FixedOffset::west(3600));
+/// assert_eq!(zones.offset("XYZT"), Some(FixedOffset::west(3600)));
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZoneTable {
+    zones: HashMap<String, i32>,
+}
+
+impl Default for ZoneTable {
+    fn default() -> Self {
+        ZoneTable::new()
+    }
+}
+
+impl ZoneTable {
+    /// The built-in table.
+    pub fn new() -> Self {
+        ZoneTable {
+            zones: ZONES
+                .iter()
+                .map(|&(abbr, secs)| (abbr.to_string(), secs))
+                .collect(),
+        }
+    }
+
+    /// A table with no abbreviations at all.
+    pub fn empty() -> Self {
+        ZoneTable {
+            zones: HashMap::new(),
+        }
+    }
+
+    /// Add an abbreviation, replacing its offset if it was already known.
+    pub fn insert(&mut self, abbr: &str, offset: FixedOffset) {
+        self.zones
+            .insert(abbr.to_uppercase(), offset.local_minus_utc());
+    }
+
+    /// Forget an abbreviation.
+    pub fn remove(&mut self, abbr: &str) {
+        self.zones.remove(&abbr.to_uppercase());
+    }
+
+    /// The offset of `abbr`, if known.
+    pub fn offset(&self, abbr: &str) -> Option<FixedOffset> {
+        self.zones
+            .get(&abbr.to_uppercase())
+            .map(|&secs| FixedOffset::east(secs))
+    }
+
+    /// Iterate over every abbreviation and its offset, in no particular order.
+    pub fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a str, FixedOffset)> + 'a {
+        self.zones
+            .iter()
+            .map(|(abbr, &secs)| (abbr.as_str(), FixedOffset::east(secs)))
+    }
+}
+
+/// An abbreviation resolved by a `ZoneAbbrevResolver`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Resolved {
+    /// The offset the abbreviation stands for.
+    pub offset: FixedOffset,
+    /// The abbreviation is ambiguous, and neither a mapping nor a preferred
+    /// region picked its meaning, so the default one was used.
+    pub by_default: bool,
+}
+
+/// Picks the meaning of ambiguous abbreviations like `IST` (India, Ireland or
+/// Israel), `CST` (US Central, China or Cuba) and `BST` (British or Bangladesh).
+///
+/// Explicit mappings win, then the first preferred region that has a meaning
+/// for the abbreviation, then the `ZoneTable`.
+///
+/// ```rust
+/// # extern crate chrono; extern crate rfc822_sanitizer;
+/// # use chrono::FixedOffset;
+/// use rfc822_sanitizer::zone::{Region, ZoneAbbrevResolver, ZoneTable};
+///
+/// # fn main() {
+/// let zones = ZoneTable::new();
+/// let resolver = ZoneAbbrevResolver::new()
+///     .prefer(Region::Europe)
+///     .map("CST", FixedOffset::east(8 * 3600));
+///
+/// // Irish Standard Time.
+/// let ist = resolver.resolve("IST", &zones).unwrap();
+/// assert_eq!(ist.offset, FixedOffset::east(3600));
+/// assert!(!ist.by_default);
+///
+/// // China Standard Time.
+/// let cst = resolver.resolve("CST", &zones).unwrap();
+/// assert_eq!(cst.offset, FixedOffset::east(8 * 3600));
+///
+/// // No European meaning, so it falls back to Samoa.
+/// let sst = resolver.resolve("SST", &zones).unwrap();
+/// assert_eq!(sst.offset, FixedOffset::west(11 * 3600));
+/// assert!(sst.by_default);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ZoneAbbrevResolver {
+    regions: Vec<Region>,
+    mappings: HashMap<String, i32>,
+}
+
+impl ZoneAbbrevResolver {
+    /// A resolver that always picks the default meaning.
+    pub fn new() -> Self {
+        ZoneAbbrevResolver::default()
+    }
+
+    /// Prefer the meaning used in `region`.
+    ///
+    /// Can be called more than once, earlier regions win.
+    pub fn prefer(mut self, region: Region) -> Self {
+        self.regions.push(region);
+        self
+    }
+
+    /// Always resolve `abbr` to `offset`.
+    pub fn map(mut self, abbr: &str, offset: FixedOffset) -> Self {
+        self.mappings
+            .insert(abbr.to_uppercase(), offset.local_minus_utc());
+        self
+    }
+
+    /// Returns `true` if it never picks anything but the default meaning.
+    pub fn is_default(&self) -> bool {
+        self.regions.is_empty() && self.mappings.is_empty()
+    }
+
+    /// Resolve `abbr`, falling back to `table`.
+    pub fn resolve(&self, abbr: &str, table: &ZoneTable) -> Option<Resolved> {
+        let key = abbr.to_uppercase();
+        if let Some(&secs) = self.mappings.get(&key) {
+            return Some(Resolved {
+                offset: FixedOffset::east(secs),
+                by_default: false,
+            });
+        }
+
+        let meanings = AMBIGUOUS
+            .iter()
+            .find(|&&(name, _)| name == key)
+            .map(|&(_, meanings)| meanings);
+        let preferred = meanings.and_then(|meanings| {
+            self.regions
+                .iter()
+                .filter_map(|r| meanings.iter().find(|&&(region, _)| region == *r))
+                .next()
+        });
+        if let Some(&(_, secs)) = preferred {
+            return Some(Resolved {
+                offset: FixedOffset::east(secs),
+                by_default: false,
+            });
+        }
+
+        table.offset(abbr).map(|offset| Resolved {
+            offset,
+            by_default: meanings.is_some(),
+        })
+    }
+}
+
+/// The offset chrono gives `abbr` on its own, if it knows the name.
+pub(crate) fn rfc2822_offset(abbr: &str) -> Option<FixedOffset> {
+    RFC2822_ZONES
+        .iter()
+        .find(|&&(name, _)| name.eq_ignore_ascii_case(abbr))
+        .map(|&(_, secs)| FixedOffset::east(secs))
+}
+
+/// Format `offset` as RFC 2822 `+HHMM`.
+pub(crate) fn format_offset(offset: FixedOffset) -> String {
+    let secs = offset.local_minus_utc();
+    let sign = if secs < 0 { '-' } else { '+' };
+    let mins = secs.abs() / 60;
+    format!("{}{:02}{:02}", sign, mins / 60, mins % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zone_table() {
+        let zones = ZoneTable::new();
+        let foo = [
+            ("CET", Some(3600)),
+            ("cest", Some(2 * 3600)),
+            ("BST", Some(3600)),
+            ("IST", Some(5 * 3600 + 1800)),
+            ("AEST", Some(10 * 3600)),
+            ("JST", Some(9 * 3600)),
+            ("MSK", Some(3 * 3600)),
+            ("NZDT", Some(13 * 3600)),
+            ("SGT", Some(8 * 3600)),
+            ("NST", Some(-(3 * 3600 + 1800))),
+            ("UTC", Some(0)),
+            ("FOO", None),
+        ];
+
+        foo.iter()
+            .for_each(|&(abbr, secs)| assert_eq!(zones.offset(abbr), secs.map(FixedOffset::east)));
+
+        let mut zones = ZoneTable::empty();
+        assert_eq!(zones.offset("CET"), None);
+        zones.insert("cet", FixedOffset::east(3600));
+        assert_eq!(zones.offset("CET"), Some(FixedOffset::east(3600)));
+        assert_eq!(zones.iter().count(), 1);
+        zones.remove("Cet");
+        assert_eq!(zones.offset("CET"), None);
+    }
+
+    #[test]
+    fn test_resolver() {
+        let zones = ZoneTable::new();
+        let resolve = |resolver: &ZoneAbbrevResolver, abbr| {
+            resolver
+                .resolve(abbr, &zones)
+                .map(|r| (r.offset.local_minus_utc(), r.by_default))
+        };
+
+        let default = ZoneAbbrevResolver::new();
+        assert!(default.is_default());
+        assert_eq!(resolve(&default, "IST"), Some((5 * 3600 + 1800, true)));
+        assert_eq!(resolve(&default, "CST"), Some((-6 * 3600, true)));
+        assert_eq!(resolve(&default, "BST"), Some((3600, true)));
+        assert_eq!(resolve(&default, "CET"), Some((3600, false)));
+        assert_eq!(resolve(&default, "FOO"), None);
+
+        let asia = ZoneAbbrevResolver::new()
+            .prefer(Region::EastAsia)
+            .prefer(Region::SouthAsia);
+        assert!(!asia.is_default());
+        assert_eq!(resolve(&asia, "CST"), Some((8 * 3600, false)));
+        assert_eq!(resolve(&asia, "BST"), Some((6 * 3600, false)));
+        assert_eq!(resolve(&asia, "ist"), Some((5 * 3600 + 1800, false)));
+        assert_eq!(resolve(&asia, "CDT"), Some((-5 * 3600, true)));
+
+        let mapped = ZoneAbbrevResolver::new()
+            .prefer(Region::Europe)
+            .map("ist", FixedOffset::east(2 * 3600))
+            .map("FOO", FixedOffset::west(3600));
+        assert_eq!(resolve(&mapped, "IST"), Some((2 * 3600, false)));
+        assert_eq!(resolve(&mapped, "BST"), Some((3600, false)));
+        assert_eq!(resolve(&mapped, "FOO"), Some((-3600, false)));
+    }
+
+    #[test]
+    fn test_format_offset() {
+        let foo = [
+            (0, "+0000"),
+            (3600, "+0100"),
+            (5 * 3600 + 1800, "+0530"),
+            (5 * 3600 + 45 * 60, "+0545"),
+            (-(3 * 3600 + 1800), "-0330"),
+            (-8 * 3600, "-0800"),
+        ];
+
+        foo.iter()
+            .for_each(|&(secs, good)| assert_eq!(format_offset(FixedOffset::east(secs)), good));
+    }
+}